@@ -2,8 +2,7 @@
 compile_error!("only linux is supported");
 use crate::Color::*;
 use async_std::task::sleep;
-use cattocol::by_lines;
-use seven_seg::sevseg_four;
+use seven_seg::{sevseg_four, Dashboard, Sparkline};
 use std::time::Duration;
 use txtframe::{Color, FrameVar, TextFrame};
 
@@ -23,51 +22,48 @@ async fn main() {
         .color_txt(Red);
 
     let mut label_frame = TextFrame::new().color_fra(Cyan).color_txt(Green);
+    label_frame.set_width(25);
 
-    loop {
-        let mut cores_freq = String::new();
-        let mut frame_core_freq = String::new();
-        let mut col_lines = 0;
-
-        for cur_cpu_freq in cpu_freq::get().iter().enumerate().take(12) {
-            let core_freq = sevseg_four(&(cur_cpu_freq.1.cur.unwrap() as u16).to_string()).unwrap();
-            let freq = freq_frame.frame_iterln(&core_freq);
-            let mut tmp_cores_freq = String::new();
-            label_frame.set_width(25);
+    let mut histories = vec![Sparkline::new(60); 12];
 
-            if cur_cpu_freq.0 > 2 {
-                col_lines += 1;
+    loop {
+        let panels = cpu_freq::get()
+            .iter()
+            .enumerate()
+            .take(12)
+            .zip(histories.iter_mut())
+            .map(|((core, cpu_freq), history)| {
+                let cur = cpu_freq.cur.unwrap();
+                history.push(f64::from(cur));
+                let core_freq = history.render(&sevseg_four(&(cur as u16).to_string()).unwrap());
 
-                frame_core_freq.push_str(
-                    &freq
-                        .chain(
-                            label_frame
-                                .frame_iterln(&format!("Cpu core: {} frequency", cur_cpu_freq.0)),
-                        )
+                (
+                    freq_frame.frame_iterln(&core_freq).collect::<String>(),
+                    label_frame
+                        .frame_iterln(&format!("Cpu core: {} frequency", core))
                         .collect::<String>(),
-                );
+                )
+            })
+            .collect::<Vec<_>>();
 
-                if col_lines == 3 {
-                    tmp_cores_freq
-                        .push_str(&by_lines(&cores_freq, &frame_core_freq).collect::<String>());
-                    frame_core_freq.clear();
-                    col_lines = 0;
-                    cores_freq.clear();
-                    cores_freq.push_str(&tmp_cores_freq);
+        // The cores run down the columns, three to a column.
+        let columns = panels.len().div_ceil(3);
+        let mut dashboard = Dashboard::grid(columns).column_spacing(0);
+        for idx in 0..columns * 3 {
+            match panels.get(idx % columns * 3 + idx / columns) {
+                Some((core_freq, label)) => {
+                    dashboard.push(core_freq.as_str(), Some(label.as_str()))
                 }
-            } else {
-                cores_freq.push_str(
-                    &freq
-                        .chain(
-                            label_frame
-                                .frame_iterln(&format!("Cpu core: {} frequency", cur_cpu_freq.0)),
-                        )
-                        .collect::<String>(),
-                );
+                None => dashboard.push("", None::<&str>),
             }
         }
 
-        print!("{}\x1b[u", main_frame.frame_iter_esc(&cores_freq).collect::<String>());
+        print!(
+            "{}\x1b[u",
+            main_frame
+                .frame_iter_esc(&dashboard.render())
+                .collect::<String>()
+        );
 
         sleep(Duration::from_millis(500)).await;
     }
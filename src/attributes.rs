@@ -0,0 +1,205 @@
+//! Blink, invert and dim attributes of single digits.
+use crate::render::{write_cell_row, GlyphStyle};
+use crate::segments::str_segments;
+use crate::{DigitSize, Segments};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::ops::{BitOr, BitOrAssign};
+
+/// Set of display attributes of a digit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::Attributes;
+/// let attributes = Attributes::BLINK | Attributes::INVERT;
+///
+/// assert!(attributes.contains(Attributes::BLINK));
+/// assert!(!attributes.contains(Attributes::DIM));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attributes(u8);
+
+impl Attributes {
+    /// No attribute.
+    pub const NONE: Attributes = Attributes(0);
+    /// Blinking digit.
+    pub const BLINK: Attributes = Attributes(1);
+    /// Digit with swapped foreground and background colors.
+    pub const INVERT: Attributes = Attributes(1 << 1);
+    /// Digit with decreased intensity.
+    pub const DIM: Attributes = Attributes(1 << 2);
+
+    /// Returns `true` if all attributes of `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Attributes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no attribute is set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// SGR parameters of the attributes, blink only if `blink` is set.
+    fn sgr_codes(self, blink: bool) -> Vec<&'static str> {
+        [
+            (Attributes::DIM, "2"),
+            (Attributes::BLINK, "5"),
+            (Attributes::INVERT, "7"),
+        ]
+        .into_iter()
+        .filter(|&(attribute, _)| {
+            self.contains(attribute) && (blink || attribute != Attributes::BLINK)
+        })
+        .map(|(_, code)| code)
+        .collect()
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Attributes;
+
+    #[inline]
+    fn bitor(self, rhs: Attributes) -> Attributes {
+        Attributes(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Attributes) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Digits with display attributes, such as the blinking digit being edited.
+///
+/// [`render`](AttributedDigits::render) emits the attributes as ANSI escape sequences. Terminals
+/// without blink support can show the two [`blink_frames`](AttributedDigits::blink_frames) in
+/// turn instead.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_two, AttributedDigits, Attributes};
+/// let digits = AttributedDigits::new("12")
+///     .unwrap()
+///     .attributes(1, Attributes::BLINK);
+///
+/// assert_eq!(digits.render(), "    ╻ \x1b[5m╺━━━┓\x1b[0m\n\
+///                              \x20   ┃ \x1b[5m    ┃\x1b[0m\n\
+///                              \x20   ┃ \x1b[5m┏━━━┛\x1b[0m\n\
+///                              \x20   ┃ \x1b[5m┃    \x1b[0m\n\
+///                              \x20   ╹ \x1b[5m┗━━━╸\x1b[0m\n"
+/// );
+///
+/// let [on, off] = digits.blink_frames();
+/// assert_eq!(on, sevseg_two("12").unwrap());
+/// assert_eq!(off, "    ╻      \n    ┃      \n    ┃      \n    ┃      \n    ╹      \n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributedDigits {
+    digits: Vec<(Segments, Attributes)>,
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+}
+
+impl AttributedDigits {
+    /// Digits from zero to nine, dashes and spaces without attributes, a point sets the decimal
+    /// point of the digit before.
+    ///
+    /// Returns `None` if the text has any other character.
+    pub fn new(text: &str) -> Option<Self> {
+        let digits = str_segments(text)
+            .map(|segments| segments.map(|segments| (segments, Attributes::NONE)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(AttributedDigits {
+            digits,
+            size: DigitSize::NORMAL,
+            glyph_style: GlyphStyle::Heavy,
+        })
+    }
+
+    /// Sets the attributes of the digit at the zero based index, an index past the last digit is
+    /// ignored.
+    pub fn attributes(mut self, idx: usize, attributes: Attributes) -> Self {
+        self.set_attributes(idx, attributes);
+        self
+    }
+
+    /// Sets the attributes of the digit at the zero based index, an index past the last digit is
+    /// ignored.
+    pub fn set_attributes(&mut self, idx: usize, attributes: Attributes) {
+        if let Some((_, digit_attributes)) = self.digits.get_mut(idx) {
+            *digit_attributes = attributes;
+        }
+    }
+
+    /// Sets the size of the digits.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters the digits are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// The digits with the attributes as SGR escape sequences.
+    pub fn render(&self) -> String {
+        self.frame(true, true)
+    }
+
+    /// Two frames to show in turn, with the blinking digits lit and blank.
+    ///
+    /// Invert and dim are escape sequences in both frames.
+    pub fn blink_frames(&self) -> [String; 2] {
+        [self.frame(false, true), self.frame(false, false)]
+    }
+
+    fn frame(&self, blink: bool, lit: bool) -> String {
+        let mut text = String::new();
+        for row in 0..self.size.rows() {
+            for (idx, &(segments, attributes)) in self.digits.iter().enumerate() {
+                if idx > 0 {
+                    text.push(' ');
+                }
+                let codes = attributes.sgr_codes(blink);
+                if !codes.is_empty() {
+                    let _ = write!(text, "\x1b[{}m", codes.join(";"));
+                }
+
+                if lit || !attributes.contains(Attributes::BLINK) {
+                    let _ = write_cell_row(
+                        &mut text,
+                        (false, segments),
+                        self.size,
+                        self.glyph_style,
+                        row,
+                    );
+                } else {
+                    let width = self.size.columns()
+                        + if segments.contains(Segments::DP) {
+                            1
+                        } else {
+                            0
+                        };
+                    text.extend(core::iter::repeat_n(' ', width));
+                }
+
+                if !codes.is_empty() {
+                    text.push_str("\x1b[0m");
+                }
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+}
@@ -0,0 +1,236 @@
+//! Segment bar graphs beside the digits.
+use crate::render::GlyphStyle;
+use crate::DigitSize;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Direction the segments of a [`BarGraph`] light up in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BarDirection {
+    /// Segments side by side, lit from the left.
+    Horizontal,
+    /// Segments stacked, lit from the bottom.
+    Vertical,
+}
+
+/// Bar graph of a value in lit and unlit segments, like an LED bar graph module.
+///
+/// A horizontal bar is as high as the digits of its [`DigitSize`], a vertical bar has a line for
+/// every segment and is padded at the top to the height of the digits, so a bar can be joined
+/// beside the digits line by line. A vertical bar has at most a segment for every line of the
+/// digits. Segments can have an SGR foreground color, such as `32` for
+/// green, that changes at thresholds of the value.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::BarGraph;
+/// let bar = BarGraph::new(4);
+///
+/// assert_eq!(bar.lit_segments(60.0), 2);
+/// assert_eq!(bar.render(60.0), "┃ ┃ ╏ ╏\n".repeat(5));
+/// ```
+///
+/// ```
+/// # use seven_seg::{sevseg_four_dp, BarDirection, BarGraph};
+/// use cattocol::by_lines;
+///
+/// let bar = BarGraph::new(5).direction(BarDirection::Vertical).range(0.0, 10.0);
+/// let display = by_lines(&sevseg_four_dp(4.2), &bar.render(4.2)).collect::<String>();
+///
+/// assert_eq!(display.lines().collect::<Vec<_>>(), [
+///     "╻   ╻  ╺━━━┓ ┏━━━┓ ┏━━━┓ ╍╍╍",
+///     "┃   ┃      ┃ ┃   ┃ ┃   ┃ ╍╍╍",
+///     "┗━━━┫  ┏━━━┛ ┃   ┃ ┃   ┃ ╍╍╍",
+///     "    ┃  ┃     ┃   ┃ ┃   ┃ ━━━",
+///     "    ╹⦁ ┗━━━╸ ┗━━━┛ ┗━━━┛ ━━━",
+/// ]);
+/// ```
+///
+/// ```
+/// # use seven_seg::BarGraph;
+/// let bar = BarGraph::new(4).color(32).threshold(50.0, 33).threshold(75.0, 31);
+///
+/// assert_eq!(bar.render(90.0).lines().next(), Some("\x1b[32m┃ ┃ \x1b[33m┃ \x1b[31m┃\x1b[0m"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarGraph {
+    segments: usize,
+    direction: BarDirection,
+    min: f64,
+    max: f64,
+    color: Option<u8>,
+    thresholds: Vec<(f64, u8)>,
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+}
+
+impl BarGraph {
+    /// Horizontal bar graph of a number of segments for percentages from 0 to 100 without colors.
+    pub fn new(segments: usize) -> Self {
+        BarGraph {
+            segments,
+            direction: BarDirection::Horizontal,
+            min: 0.0,
+            max: 100.0,
+            color: None,
+            thresholds: Vec::new(),
+            size: DigitSize::NORMAL,
+            glyph_style: GlyphStyle::Heavy,
+        }
+    }
+
+    /// Sets the direction the segments light up in.
+    pub fn direction(mut self, direction: BarDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the values of no and of all lit segments.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets the SGR foreground color of the segments below the first threshold.
+    pub fn color(mut self, color: u8) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Adds a threshold of the value from which on the segments have an SGR foreground color.
+    pub fn threshold(mut self, value: f64, color: u8) -> Self {
+        let idx = self
+            .thresholds
+            .partition_point(|&(threshold, _)| threshold <= value);
+        self.thresholds.insert(idx, (value, color));
+        self
+    }
+
+    /// Sets the size of the digits the bar is as high as.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters the segments are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// Number of segments lit by a value, rounded to the nearest segment and none for NaN.
+    pub fn lit_segments(&self, value: f64) -> usize {
+        lit_count(value, self.min, self.max, self.segment_count())
+    }
+
+    /// Number of segments drawn, those of a vertical bar capped to the lines of the digits.
+    fn segment_count(&self) -> usize {
+        match self.direction {
+            BarDirection::Horizontal => self.segments,
+            BarDirection::Vertical => self.segments.min(self.size.rows()),
+        }
+    }
+
+    /// The bar graph of a value with color escape sequences if any color is set.
+    pub fn render(&self, value: f64) -> String {
+        let lit = self.lit_segments(value);
+        let rows = self.size.rows();
+        let segments = self.segment_count();
+
+        let mut text = String::new();
+        match self.direction {
+            BarDirection::Horizontal => {
+                for _ in 0..rows {
+                    self.write_line(&mut text, (0..segments).map(|idx| (idx, 1)), lit);
+                }
+            }
+            BarDirection::Vertical => {
+                let width = self.size.columns() - 2;
+                for _ in segments..rows {
+                    text.extend(core::iter::repeat_n(' ', width));
+                    text.push('\n');
+                }
+                for idx in (0..segments).rev() {
+                    self.write_line(&mut text, core::iter::once((idx, width)), lit);
+                }
+            }
+        }
+
+        text
+    }
+
+    /// Writes a line of segments, each repeated a number of columns and separated by a space.
+    fn write_line<I>(&self, text: &mut String, segments: I, lit: usize)
+    where
+        I: Iterator<Item = (usize, usize)>,
+    {
+        let mut current = None;
+        for (pos, (idx, columns)) in segments.enumerate() {
+            if pos > 0 {
+                text.push(' ');
+            }
+            let color = self.segment_color(idx);
+            if let Some(code) = color.filter(|_| color != current) {
+                let _ = write!(text, "\x1b[{}m", code);
+                current = color;
+            }
+            let chr = segment_char(self.direction, self.glyph_style, idx < lit);
+            text.extend(core::iter::repeat_n(chr, columns));
+        }
+        if current.is_some() {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+
+    /// Color of the last threshold at or below the value the segment starts at.
+    fn segment_color(&self, idx: usize) -> Option<u8> {
+        let start = self.min + (self.max - self.min) * idx as f64 / self.segment_count() as f64;
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|&&(threshold, _)| threshold <= start)
+            .map(|&(_, color)| color)
+            .or(self.color)
+    }
+}
+
+/// Number of a count of segments lit by a value in a range, rounded to the nearest segment and
+/// none for NaN.
+pub(crate) fn lit_count(value: f64, min: f64, max: f64, count: usize) -> usize {
+    let fraction = if max > min {
+        (value - min) / (max - min)
+    } else if value >= max {
+        1.0
+    } else {
+        0.0
+    };
+    if fraction.is_nan() {
+        return 0;
+    }
+
+    // Half a segment more truncated is rounding without `std`, the segments are not negative.
+    ((fraction.clamp(0.0, 1.0) * count as f64 + 0.5) as usize).min(count)
+}
+
+/// Character of a lit or unlit segment of a bar in a direction, the segments of a horizontal bar
+/// are vertical lines.
+pub(crate) fn segment_char(direction: BarDirection, glyph_style: GlyphStyle, lit: bool) -> char {
+    match (direction, glyph_style, lit) {
+        (BarDirection::Horizontal, GlyphStyle::Heavy, true) => '┃',
+        (BarDirection::Horizontal, GlyphStyle::Heavy, false) => '╏',
+        (BarDirection::Horizontal, GlyphStyle::Light, true) => '│',
+        (BarDirection::Horizontal, GlyphStyle::Light, false) => '╎',
+        (BarDirection::Horizontal, GlyphStyle::Ascii, true) => '|',
+        (BarDirection::Horizontal, GlyphStyle::Ascii, false) => ':',
+        (BarDirection::Vertical, GlyphStyle::Heavy, true) => '━',
+        (BarDirection::Vertical, GlyphStyle::Heavy, false) => '╍',
+        (BarDirection::Vertical, GlyphStyle::Light, true) => '─',
+        (BarDirection::Vertical, GlyphStyle::Light, false) => '╌',
+        (BarDirection::Vertical, GlyphStyle::Ascii, true) => '=',
+        (BarDirection::Vertical, GlyphStyle::Ascii, false) => '-',
+    }
+}
@@ -0,0 +1,317 @@
+//! Full screen clock, timer and stopwatch.
+use crate::Options;
+use chrono::{Local, Timelike};
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use seven_seg::{sevseg_frame_size, sevseg_render_with, LiveDisplay};
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, Instant};
+
+/// Laps shown below a stopwatch.
+const LAPS: usize = 5;
+
+/// What the full screen display shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Clock,
+    Timer(Duration),
+    Stopwatch,
+}
+
+/// Parses a duration like `90`, `90s`, `1h30m`, `1:30` or `1:00:00`.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration {}", text);
+
+    let secs = if text.contains(':') {
+        let parts = text
+            .split(':')
+            .map(|part| part.parse::<u64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if parts.len() > 3 {
+            return Err(invalid());
+        }
+        parts
+            .into_iter()
+            .try_fold(0_u64, |secs, part| secs.checked_mul(60)?.checked_add(part))
+            .ok_or_else(invalid)?
+    } else {
+        let mut secs = 0_u64;
+        let mut number = String::new();
+        for chr in text.chars() {
+            let unit = match chr {
+                '0'..='9' => {
+                    number.push(chr);
+                    continue;
+                }
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return Err(invalid()),
+            };
+            let part = number.parse::<u64>().map_err(|_| invalid())?;
+            secs = part
+                .checked_mul(unit)
+                .and_then(|part| secs.checked_add(part))
+                .ok_or_else(invalid)?;
+            number.clear();
+        }
+        if !number.is_empty() {
+            let part = number.parse::<u64>().map_err(|_| invalid())?;
+            secs = secs.checked_add(part).ok_or_else(invalid)?;
+        }
+        secs
+    };
+
+    if text.is_empty() || secs == 0 {
+        return Err(invalid());
+    }
+
+    Ok(Duration::from_secs(secs))
+}
+
+/// Elapsed time that can be paused.
+#[derive(Debug, Clone, Copy)]
+struct Watch {
+    started: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl Watch {
+    fn new() -> Self {
+        Watch {
+            started: Some(Instant::now()),
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.accumulated
+            + self
+                .started
+                .map_or(Duration::ZERO, |started| started.elapsed())
+    }
+
+    fn running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    fn toggle(&mut self) {
+        match self.started.take() {
+            Some(started) => self.accumulated += started.elapsed(),
+            None => self.started = Some(Instant::now()),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        if self.running() {
+            self.started = Some(Instant::now());
+        }
+    }
+}
+
+/// Hours, minutes and seconds of a duration, tenths of a second for a stopwatch.
+fn duration_parts(duration: Duration, tenths: bool) -> Vec<String> {
+    let secs = duration.as_secs();
+    let mut parts = Vec::new();
+    if secs >= 3600 {
+        parts.push((secs / 3600).to_string());
+    }
+    parts.push(format!("{:02}", secs / 60 % 60));
+    parts.push(if tenths {
+        format!("{:02}.{}", secs % 60, duration.subsec_millis() / 100)
+    } else {
+        format!("{:02}", secs % 60)
+    });
+
+    parts
+}
+
+/// The parts rendered side by side with colons between them.
+fn render_time(options: &Options, parts: &[String], colon: bool) -> String {
+    let size = options.size;
+    let rows = size.rows();
+    let middle = (rows - 1) / 2;
+    let dots = [middle / 2, middle + (rows - middle) / 2];
+    let dot = options.style.point_char();
+
+    let blocks = parts
+        .iter()
+        .map(|part| {
+            let mut buf = vec![0; size.buffer_len(part.chars().count())];
+            sevseg_render_with(part, size, options.style, &mut buf)
+                .unwrap_or_default()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut text = String::new();
+    for row in 0..rows {
+        if let Some(code) = options.color {
+            text.push_str(&format!("\x1b[{}m", code));
+        }
+        for (idx, block) in blocks.iter().enumerate() {
+            if idx > 0 {
+                text.push(' ');
+                text.push(if colon && dots.contains(&row) {
+                    dot
+                } else {
+                    ' '
+                });
+                text.push(' ');
+            }
+            text.push_str(block.get(row).map_or("", String::as_str));
+        }
+        if options.color.is_some() {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Restores the terminal when dropped.
+struct Screen {
+    live: LiveDisplay<Stdout>,
+    origin: (u16, u16),
+}
+
+impl Screen {
+    fn new() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
+
+        Ok(Screen {
+            live: LiveDisplay::new(stdout),
+            origin: (u16::MAX, u16::MAX),
+        })
+    }
+
+    /// Draws the frame in the middle of the terminal.
+    fn draw(&mut self, frame: &str) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (width, height) = sevseg_frame_size(frame);
+        let origin = (
+            columns.saturating_sub(width as u16) / 2,
+            rows.saturating_sub(height as u16) / 2,
+        );
+
+        if origin != self.origin {
+            self.origin = origin;
+            queue!(self.live.get_mut(), Clear(ClearType::All))?;
+            self.live.set_origin(origin.0, origin.1);
+        }
+
+        self.live.draw(frame)
+    }
+
+    fn resize(&mut self) -> io::Result<()> {
+        queue!(self.live.get_mut(), Clear(ClearType::All))?;
+        self.live.invalidate();
+        Ok(())
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(self.live.get_mut(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Runs the full screen display until `q`, escape or control c.
+pub fn run(options: &Options, mode: Mode) -> io::Result<()> {
+    let mut screen = Screen::new()?;
+    let mut watch = Watch::new();
+    let mut laps: Vec<Duration> = Vec::new();
+    let mut rang = false;
+
+    loop {
+        let (parts, colon, help) = match mode {
+            Mode::Clock => {
+                let now = Local::now();
+                let parts = [now.hour(), now.minute(), now.second()]
+                    .iter()
+                    .map(|part| format!("{:02}", part))
+                    .collect::<Vec<_>>();
+                (parts, now.nanosecond() < 500_000_000, "q quit")
+            }
+            Mode::Timer(duration) => {
+                let remaining = duration.saturating_sub(watch.elapsed());
+                let done = remaining.is_zero();
+                if done && !rang {
+                    rang = true;
+                    screen.live.get_mut().write_all(b"\x07")?;
+                }
+                // Rounded up, the timer shows zero only when it has run out.
+                let shown = Duration::from_secs(remaining.as_secs_f64().ceil() as u64);
+                let blink = if done {
+                    watch.elapsed().subsec_millis() < 500
+                } else {
+                    remaining.subsec_millis() >= 500 || !watch.running()
+                };
+                (
+                    duration_parts(shown, false),
+                    blink,
+                    "space pause  r reset  q quit",
+                )
+            }
+            Mode::Stopwatch => {
+                let elapsed = watch.elapsed();
+                let blink = elapsed.subsec_millis() < 500 || !watch.running();
+                (
+                    duration_parts(elapsed, true),
+                    blink,
+                    "space pause  r reset  l lap  q quit",
+                )
+            }
+        };
+
+        let mut frame = render_time(options, &parts, colon);
+        frame.push('\n');
+        frame.push_str(help);
+        frame.push('\n');
+        for (idx, lap) in laps.iter().enumerate().rev().take(LAPS) {
+            frame.push_str(&format!(
+                "\nLap {:>2}  {}",
+                idx + 1,
+                duration_parts(*lap, true).join(":")
+            ));
+        }
+        frame.push('\n');
+        screen.draw(&frame)?;
+
+        if !event::poll(Duration::from_millis(50))? {
+            continue;
+        }
+        match event::read()? {
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) => match code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break,
+                KeyCode::Char(' ') | KeyCode::Char('p') if mode != Mode::Clock => watch.toggle(),
+                KeyCode::Char('r') if mode != Mode::Clock => {
+                    watch.reset();
+                    laps.clear();
+                    rang = false;
+                }
+                KeyCode::Char('l') if mode == Mode::Stopwatch => laps.push(watch.elapsed()),
+                _ => {}
+            },
+            Event::Resize(..) => screen.resize()?,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
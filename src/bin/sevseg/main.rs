@@ -0,0 +1,325 @@
+//! Seven segment display of the arguments, of the lines of the standard input or of the output of
+//! a command, and a full screen clock, timer and stopwatch.
+mod clock;
+
+use clock::Mode;
+use crossterm::execute;
+use crossterm::terminal::{Clear, ClearType};
+use seven_seg::{sevseg_render_with, DigitSize, GlyphStyle, LiveDisplay};
+use std::io::{self, BufRead, Write};
+use std::process::{Command, ExitCode};
+use std::time::Duration;
+use std::{env, thread};
+
+const USAGE: &str = "\
+Usage: sevseg [OPTIONS] [TEXT]...
+       sevseg [OPTIONS] clock
+       sevseg [OPTIONS] timer <DURATION>
+       sevseg [OPTIONS] stopwatch
+
+Shows the text, or every line of the standard input without text, in seven segment digits.
+Digits, dashes, spaces and points can be displayed.
+
+Commands:
+  clock                    Full screen clock of the local time
+  timer <DURATION>         Full screen countdown, such as 90, 90s, 5m, 1h30m or 1:30
+  stopwatch                Full screen stopwatch
+
+Keys of the full screen commands: space pauses, r resets, l takes a lap, q quits.
+
+Options:
+  -n, --digits <N>         Number of digits, the text is padded with blanks
+  -s, --style <STYLE>      heavy, light or ascii [default: heavy]
+  -z, --size <SIZE>        small, normal, large or huge [default: normal]
+  -c, --color <COLOR>      black, red, green, yellow, blue, magenta, cyan or white
+  -p, --decimals <N>       Shows the text as a number with N decimal places
+  -a, --align <ALIGN>      left, center or right in the digits [default: right]
+  -w, --watch <COMMAND>    Shows the output of the command, run again at every interval
+  -i, --interval <SECS>    Seconds between two runs of the watched command [default: 2]
+  -h, --help               Prints this help
+  -V, --version            Prints the version";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    text: Vec<String>,
+    digits: Option<usize>,
+    style: GlyphStyle,
+    size: DigitSize,
+    color: Option<u8>,
+    decimals: Option<usize>,
+    align: Align,
+    watch: Option<String>,
+    interval: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            text: Vec::new(),
+            digits: None,
+            style: GlyphStyle::Heavy,
+            size: DigitSize::NORMAL,
+            color: None,
+            decimals: None,
+            align: Align::Right,
+            watch: None,
+            interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// What the command line asks for.
+#[derive(Debug, Clone, PartialEq)]
+enum Request {
+    Run(Options),
+    Help,
+    Version,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Request, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            options.text.extend(args.by_ref());
+            break;
+        }
+        if !arg.starts_with('-') || arg == "-" || arg.parse::<f64>().is_ok() {
+            options.text.push(arg);
+            continue;
+        }
+
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag.to_string(), Some(value.into())),
+            _ => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value of {}", flag))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Request::Help),
+            "-V" | "--version" => return Ok(Request::Version),
+            "-n" | "--digits" => options.digits = Some(parse_number(&value()?)?),
+            "-s" | "--style" => {
+                options.style = match value()?.as_str() {
+                    "heavy" => GlyphStyle::Heavy,
+                    "light" => GlyphStyle::Light,
+                    "ascii" => GlyphStyle::Ascii,
+                    style => return Err(format!("unknown style {}", style)),
+                }
+            }
+            "-z" | "--size" => {
+                options.size = match value()?.as_str() {
+                    "small" => DigitSize::SMALL,
+                    "normal" => DigitSize::NORMAL,
+                    "large" => DigitSize::LARGE,
+                    "huge" => DigitSize::HUGE,
+                    size => return Err(format!("unknown size {}", size)),
+                }
+            }
+            "-c" | "--color" => {
+                let color = value()?;
+                let code = [
+                    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+                ]
+                .iter()
+                .position(|&name| name == color)
+                .ok_or_else(|| format!("unknown color {}", color))?;
+                options.color = Some(30 + code as u8);
+            }
+            "-p" | "--decimals" => options.decimals = Some(parse_number(&value()?)?),
+            "-a" | "--align" => {
+                options.align = match value()?.as_str() {
+                    "left" => Align::Left,
+                    "center" => Align::Center,
+                    "right" => Align::Right,
+                    align => return Err(format!("unknown alignment {}", align)),
+                }
+            }
+            "-w" | "--watch" => options.watch = Some(value()?),
+            "-i" | "--interval" => {
+                let secs = value()?;
+                options.interval = secs
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .ok_or_else(|| format!("invalid interval {}", secs))?;
+            }
+            _ => return Err(format!("unknown option {}", flag)),
+        }
+    }
+
+    Ok(Request::Run(options))
+}
+
+fn parse_number(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number {}", value))
+}
+
+/// Number of digits of a text, a point after a digit is its decimal point.
+fn digit_count(text: &str) -> usize {
+    let mut count = 0;
+    let mut after_digit = false;
+
+    for chr in text.chars() {
+        if chr == '.' && after_digit {
+            after_digit = false;
+        } else {
+            count += 1;
+            after_digit = chr != '.';
+        }
+    }
+
+    count
+}
+
+/// The seven segment display of a line of text.
+fn display(options: &Options, line: &str) -> Result<String, String> {
+    let mut text = line.trim_end_matches(['\r', '\n']).to_string();
+
+    if let Some(decimals) = options.decimals {
+        let number = text
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("not a number: {}", text))?;
+        text = format!("{:.*}", decimals, number);
+    }
+
+    if let Some(digits) = options.digits {
+        let count = digit_count(&text);
+        if count > digits {
+            return Err(format!("{} does not fit into {} digits", text, digits));
+        }
+
+        let blanks = digits - count;
+        let (left, right) = match options.align {
+            Align::Left => (0, blanks),
+            Align::Center => (blanks / 2, blanks - blanks / 2),
+            Align::Right => (blanks, 0),
+        };
+        text = format!("{}{}{}", " ".repeat(left), text, " ".repeat(right));
+    }
+
+    let size = options.size;
+    let mut buf = vec![0; size.buffer_len(text.chars().count())];
+    let rendered = sevseg_render_with(&text, size, options.style, &mut buf)
+        .ok_or_else(|| format!("cannot display {}", text))?;
+
+    Ok(match options.color {
+        Some(code) => rendered
+            .lines()
+            .map(|line| format!("\x1b[{}m{}\x1b[0m\n", code, line))
+            .collect(),
+        None => rendered.to_string(),
+    })
+}
+
+fn watch(options: &Options, command: &str) -> Result<(), String> {
+    let mut stdout = io::stdout();
+    execute!(stdout, Clear(ClearType::All)).map_err(|err| err.to_string())?;
+    let mut live = LiveDisplay::new(stdout);
+
+    loop {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .output()
+            .map_err(|err| format!("cannot run {}: {}", command, err))?;
+
+        let mut screen = String::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            match display(options, line) {
+                Ok(display) => screen.push_str(&display),
+                Err(err) => {
+                    screen.push_str(&err);
+                    screen.push('\n');
+                }
+            }
+        }
+
+        live.draw(&screen).map_err(|err| err.to_string())?;
+        thread::sleep(options.interval);
+    }
+}
+
+/// The full screen command of the text, if any.
+fn mode(text: &[String]) -> Result<Option<Mode>, String> {
+    let mode = match text.first().map(String::as_str) {
+        Some("clock") => Mode::Clock,
+        Some("stopwatch") => Mode::Stopwatch,
+        Some("timer") => match text.get(1) {
+            Some(duration) => Mode::Timer(clock::parse_duration(duration)?),
+            None => return Err("missing duration of timer".to_string()),
+        },
+        _ => return Ok(None),
+    };
+
+    let args = if let Mode::Timer(_) = mode { 2 } else { 1 };
+    if text.len() > args {
+        return Err(format!("unexpected argument {}", text[args]));
+    }
+
+    Ok(Some(mode))
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    if let Some(mode) = mode(&options.text)? {
+        return clock::run(options, mode).map_err(|err| err.to_string());
+    }
+
+    if let Some(command) = &options.watch {
+        return watch(options, command);
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut print = |line: &str| {
+        stdout
+            .write_all(display(options, line)?.as_bytes())
+            .map_err(|err| err.to_string())
+    };
+
+    if options.text.is_empty() {
+        for line in io::stdin().lock().lines() {
+            print(&line.map_err(|err| err.to_string())?)?;
+        }
+        Ok(())
+    } else {
+        print(&options.text.join(" "))
+    }
+}
+
+fn main() -> ExitCode {
+    let result = match parse_args(env::args().skip(1)) {
+        Ok(Request::Help) => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Ok(Request::Version) => {
+            println!("sevseg {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+        Ok(Request::Run(options)) => run(&options),
+        Err(err) => Err(format!("{}\n\n{}", err, USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("sevseg: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
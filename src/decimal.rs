@@ -0,0 +1,281 @@
+//! Numbers of any size and precision in a number of digits.
+use crate::number::{number_cells, number_digits, render_number};
+use crate::{NumberStyle, Segments};
+use alloc::format;
+use alloc::string::{String, ToString};
+use num::bigint::{BigInt, BigUint, Sign};
+use num::rational::Ratio;
+use num::{Integer, Signed, Zero};
+
+/// A number that can be shown in decimal digits.
+///
+/// Implemented for the primitive integers and floats, [`BigInt`], [`BigUint`] and the [`Ratio`]
+/// of integers that convert into a [`BigInt`]. Decimal types of other crates can implement it to
+/// be shown by [`sevseg_number`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_number, sevseg_render, DecimalDigits};
+/// use num::rational::Ratio;
+///
+/// /// Amount of money in cents.
+/// struct Cents(i64);
+///
+/// impl DecimalDigits for Cents {
+///     fn is_negative(&self) -> bool {
+///         self.0 < 0
+///     }
+///
+///     fn decimal_digits(&self, decimals: usize) -> Option<String> {
+///         Ratio::new(self.0, 100).decimal_digits(decimals)
+///     }
+/// }
+///
+/// let mut buf = [0; 512];
+/// assert_eq!(
+///     sevseg_number(&Cents(1250), 4).unwrap(),
+///     sevseg_render("12.50", &mut buf).unwrap()
+/// );
+/// assert_eq!(
+///     sevseg_number(&Cents(-1999), 2).unwrap(),
+///     sevseg_number(&-20, 2).unwrap()
+/// );
+/// ```
+pub trait DecimalDigits {
+    /// Returns `true` if the number is below zero.
+    fn is_negative(&self) -> bool;
+
+    /// Returns `true` if the number is not a number.
+    fn is_nan(&self) -> bool {
+        false
+    }
+
+    /// Digits of the magnitude of the number rounded to the decimal places, with a point in
+    /// front of the decimal places, such as `"0.50"`. Returns `None` for a number that is not
+    /// finite.
+    ///
+    /// The built-in implementations round half away from zero, a float by the exact binary value
+    /// it holds. So `2.5` has the digits `"3"` like `Ratio::new(5, 2)`, but `2.675` has `"2.67"`
+    /// as the float is a little below it.
+    fn decimal_digits(&self, decimals: usize) -> Option<String>;
+}
+
+/// Digits of an integer magnitude with zero decimal places.
+fn integer_digits<T: core::fmt::Display>(magnitude: T, decimals: usize) -> String {
+    if decimals == 0 {
+        magnitude.to_string()
+    } else {
+        format!("{}.{:0>2$}", magnitude, "", decimals)
+    }
+}
+
+macro_rules! signed_decimal_digits {
+    ($($int:ty),*) => {$(
+        impl DecimalDigits for $int {
+            #[inline]
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+
+            fn decimal_digits(&self, decimals: usize) -> Option<String> {
+                Some(integer_digits(self.unsigned_abs(), decimals))
+            }
+        }
+    )*};
+}
+
+macro_rules! unsigned_decimal_digits {
+    ($($int:ty),*) => {$(
+        impl DecimalDigits for $int {
+            #[inline]
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            fn decimal_digits(&self, decimals: usize) -> Option<String> {
+                Some(integer_digits(self, decimals))
+            }
+        }
+    )*};
+}
+
+macro_rules! float_decimal_digits {
+    ($($float:ty),*) => {$(
+        impl DecimalDigits for $float {
+            #[inline]
+            fn is_negative(&self) -> bool {
+                *self < 0.0
+            }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                <$float>::is_nan(*self)
+            }
+
+            fn decimal_digits(&self, decimals: usize) -> Option<String> {
+                if !self.is_finite() {
+                    return None;
+                }
+
+                Ratio::<BigInt>::from_float(self.abs())?.decimal_digits(decimals)
+            }
+        }
+    )*};
+}
+
+signed_decimal_digits!(i8, i16, i32, i64, i128, isize);
+unsigned_decimal_digits!(u8, u16, u32, u64, u128, usize);
+float_decimal_digits!(f32, f64);
+
+impl DecimalDigits for BigInt {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.sign() == Sign::Minus
+    }
+
+    fn decimal_digits(&self, decimals: usize) -> Option<String> {
+        Some(integer_digits(self.magnitude(), decimals))
+    }
+}
+
+impl DecimalDigits for BigUint {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn decimal_digits(&self, decimals: usize) -> Option<String> {
+        Some(integer_digits(self, decimals))
+    }
+}
+
+/// The quotient is computed without overflow in a [`BigInt`].
+impl<T> DecimalDigits for Ratio<T>
+where
+    T: Clone + Integer + Into<BigInt>,
+{
+    fn is_negative(&self) -> bool {
+        let zero = T::zero();
+        !self.numer().is_zero() && (*self.numer() < zero) != (*self.denom() < zero)
+    }
+
+    fn decimal_digits(&self, decimals: usize) -> Option<String> {
+        let numer: BigInt = self.numer().clone().into();
+        let denom: BigInt = self.denom().clone().into();
+        let (numer, denom) = (numer.abs(), denom.abs());
+        if denom.is_zero() {
+            return None;
+        }
+
+        let scaled = numer * num::pow(BigInt::from(10), decimals);
+        let quotient = (scaled * 2 + &denom) / (denom * 2);
+        let digits = format!("{:0>1$}", quotient, decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+
+        Some(if decimals == 0 {
+            integer.to_string()
+        } else {
+            format!("{}.{}", integer, fraction)
+        })
+    }
+}
+
+/// Seven segment display of a number of any size and precision in a number of digits.
+///
+/// The digits the integer part leaves are decimal places, the number is rounded to them. The
+/// decimal point and the merged minus sign are those of [`sevseg_four_dp`](crate::sevseg_four_dp)
+/// and a NaN or an infinite float is shown as `nAn`, `InF` or `-InF`. Returns `None` if the
+/// integer part does not fit into the digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_number, sevseg_render};
+/// use num::{BigInt, rational::Ratio};
+///
+/// let mut buf = [0; 512];
+/// assert_eq!(
+///     sevseg_number(&Ratio::new(2, 3), 4).unwrap(),
+///     sevseg_render("0.667", &mut buf).unwrap()
+/// );
+///
+/// let big = BigInt::from(10).pow(30);
+/// assert_eq!(sevseg_number(&big, 4), None);
+/// assert_eq!(
+///     sevseg_number(&(big / BigInt::from(10).pow(27)), 6).unwrap(),
+///     sevseg_render("1000.00", &mut buf).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn sevseg_number<T: DecimalDigits + ?Sized>(n: &T, digits: usize) -> Option<String> {
+    sevseg_number_with(n, digits, NumberStyle::new())
+}
+
+/// Seven segment display of a number of any size and precision like [`sevseg_number`], in a
+/// [`NumberStyle`].
+///
+/// A NaN or an infinite float is shown as its [`NonFinite`](crate::NonFinite) representation,
+/// right aligned in the digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, sevseg_number_with, NonFinite, NumberStyle};
+/// let style = NumberStyle::new().non_finite(NonFinite::Dashes);
+///
+/// assert_eq!(sevseg_number_with(&f64::NAN, 4, style), sevseg_four("----"));
+/// assert_eq!(sevseg_number_with(&f64::NAN, 3, style), None);
+/// ```
+pub fn sevseg_number_with<T: DecimalDigits + ?Sized>(
+    n: &T,
+    digits: usize,
+    style: NumberStyle,
+) -> Option<String> {
+    let negative = n.is_negative();
+    let budget = number_digits(digits, negative, style.sign)?;
+
+    // Rounding can carry into one more integer digit, taking a decimal place.
+    let mut decimals = budget - 1;
+    let text = loop {
+        let Some(text) = n.decimal_digits(decimals) else {
+            return non_finite(n, digits, style);
+        };
+        let count = text.chars().filter(char::is_ascii_digit).count();
+        if count <= budget {
+            break text;
+        }
+        if decimals == 0 {
+            return None;
+        }
+        decimals = decimals.saturating_sub(count - budget);
+    };
+
+    render_number(&number_cells(&text, negative, style.sign), style)
+}
+
+/// The non finite representation of the style right aligned in the digits.
+fn non_finite<T: DecimalDigits + ?Sized>(
+    n: &T,
+    digits: usize,
+    style: NumberStyle,
+) -> Option<String> {
+    let name = if n.is_nan() {
+        "NaN"
+    } else if n.is_negative() {
+        "-inf"
+    } else {
+        "inf"
+    };
+    let cells = style.non_finite.cells(name)?;
+    let start = cells
+        .iter()
+        .position(|&(_, segments)| segments != Segments::NONE)
+        .unwrap_or(cells.len());
+    let blanks = digits.checked_sub(cells.len() - start)?;
+
+    let cells = core::iter::repeat_n((false, Segments::NONE), blanks)
+        .chain(cells[start..].iter().copied())
+        .collect::<alloc::vec::Vec<_>>();
+    render_number(&cells, style)
+}
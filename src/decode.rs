@@ -0,0 +1,285 @@
+//! Recognition of digits in a rendered seven segment display.
+use crate::render::{write_cell_row, Cell};
+use crate::segments::SEGMENTS;
+use crate::{DigitSize, GlyphStyle, Segments};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Characters read from a rendered seven segment display.
+///
+/// Displays as the number it shows, a merged minus sign in front and a point after every digit
+/// with a decimal point.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// Recognized characters, a space for a blank cell and `'?'` in place of an unrecognized cell.
+    pub string: String,
+    /// Character indexes of the digits followed by a decimal point.
+    pub points: Vec<usize>,
+    /// Minus sign merged in front of a digit.
+    pub negative: bool,
+    /// Character indexes of the cells that are not a known glyph.
+    pub unrecognized: Vec<usize>,
+}
+
+impl fmt::Display for Decoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+
+        for (idx, chr) in self.string.chars().enumerate() {
+            write!(f, "{}", chr)?;
+            if self.points.contains(&idx) {
+                f.write_str(".")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the digits of a rendered seven segment display.
+///
+/// Accepts digits side by side in any [`GlyphStyle`] and [`DigitSize`], with or without decimal
+/// points and merged minus signs, like the output of
+/// [`sevseg_render_with`](crate::sevseg_render_with) and of the one to four digit displays. The
+/// text is read as the glyphs of the renderer, so a cell is only ever read as the digit that
+/// draws it, blank columns between the cells are skipped. Returns `None` if the text is not a row
+/// of rendered digits, such as a display with labels or separators beside the digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_decode, sevseg_four, sevseg_four_dp};
+/// let decoded = sevseg_decode(&sevseg_four("8023").unwrap()).unwrap();
+/// assert_eq!(decoded.string, "8023");
+///
+/// let decoded = sevseg_decode(&sevseg_four_dp(-1.5_f32)).unwrap();
+/// assert_eq!(decoded.to_string(), "-1.500");
+/// assert_eq!(decoded.points, [0]);
+/// assert!(decoded.negative);
+/// ```
+///
+/// ```
+/// # use seven_seg::{sevseg_decode, sevseg_render_with, DigitSize, GlyphStyle};
+/// let mut buf = [0; 1024];
+/// let text = sevseg_render_with("-0123.4", DigitSize::LARGE, GlyphStyle::Ascii, &mut buf);
+/// let decoded = sevseg_decode(text.unwrap()).unwrap();
+///
+/// assert_eq!(decoded.string, "-01234");
+/// assert_eq!(decoded.points, [4]);
+/// assert!(!decoded.negative);
+/// ```
+pub fn sevseg_decode(text: &str) -> Option<Decoded> {
+    let rows = text
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    if rows.len() < 5 || rows.len() % 2 == 0 {
+        return None;
+    }
+
+    // A style reads only texts of the characters of its glyphs.
+    let styles = [GlyphStyle::Heavy, GlyphStyle::Light, GlyphStyle::Ascii]
+        .into_iter()
+        .filter(|&style| {
+            let alphabet = alphabet(style);
+            rows.iter()
+                .flatten()
+                .all(|chr| alphabet.binary_search(chr).is_ok())
+        })
+        .collect::<Vec<_>>();
+    if styles.is_empty() {
+        return None;
+    }
+
+    // Cells are apart, the widest run of columns is at most one cell with a point and a minus
+    // sign, and at least a digit wider than a one.
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = (0..width)
+        .map(|col| {
+            rows.iter()
+                .all(|row| row.get(col).is_none_or(|&chr| chr == ' '))
+        })
+        .collect::<Vec<_>>();
+    let widest = blank
+        .iter()
+        .fold((0_usize, 0), |(widest, run), &blank| {
+            let run = if blank { 0 } else { run + 1 };
+            (widest.max(run), run)
+        })
+        .0;
+    let vertical = (rows.len() - 3) / 2;
+    let sizes = core::iter::once(DigitSize::NORMAL)
+        .chain(DigitSize::ALL)
+        .chain(
+            (1..=widest.saturating_sub(2).max(1))
+                .map(|horizontal| DigitSize::new(horizontal, vertical))
+                .filter(|size| widest <= size.columns() + size.sign_columns() + 1),
+        )
+        .filter(|size| size.rows() == rows.len())
+        .fold(Vec::new(), |mut sizes, size| {
+            if !sizes.contains(&size) {
+                sizes.push(size);
+            }
+            sizes
+        });
+
+    // Smaller digits can draw larger ones with blank columns between them, a reading without
+    // skipped columns is the one the renderer drew.
+    let mut best = None::<(Cost, Vec<Cell>)>;
+    for size in sizes {
+        for &style in &styles {
+            if let Some((cost, cells)) = read_cells(&rows, &blank, size, style) {
+                if best.as_ref().is_none_or(|(best, _)| cost < *best) {
+                    best = Some((cost, cells));
+                }
+            }
+        }
+        if best.as_ref().is_some_and(|&((skipped, _), _)| skipped == 0) {
+            break;
+        }
+    }
+
+    best.map(|(_, cells)| decoded(&cells))
+}
+
+/// Skipped blank columns and merged minus signs of a reading, the fewer the better.
+type Cost = (usize, usize);
+
+/// Cells of the rows with their blank columns in a size and style with the fewest skipped columns
+/// and minus signs, or `None` if the rows are not made of their glyphs.
+fn read_cells(
+    rows: &[Vec<char>],
+    blank: &[bool],
+    size: DigitSize,
+    style: GlyphStyle,
+) -> Option<(Cost, Vec<Cell>)> {
+    let chr = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(' ');
+    let width = blank.len();
+    let glyphs = glyphs(size, style);
+
+    // The best reading from a column to the end with its first cell and the column after the
+    // cell and its separator, from the right to the left. Of equal readings the digits of the
+    // renderer come first.
+    let mut next = vec![None::<(Cost, Option<Cell>, usize)>; width + 1];
+    next[width] = Some(((0, 0), None, width));
+    for pos in (0..width).rev() {
+        let cells = glyphs.iter().filter_map(|&(cell, columns, ref chars)| {
+            let end = pos + columns;
+            let after = if end == width {
+                width
+            } else if end < width && blank[end] {
+                end + 1
+            } else {
+                return None;
+            };
+            let ((skipped, minus), ..) = next[after]?;
+            let same = chars.chunks(columns).enumerate().all(|(row, line)| {
+                line.iter()
+                    .enumerate()
+                    .all(|(col, &glyph)| chr(row, pos + col) == glyph)
+            });
+
+            same.then_some(((skipped, minus + usize::from(cell.0)), Some(cell), after))
+        });
+        let skip = next[pos + 1]
+            .filter(|_| blank[pos])
+            .map(|((skipped, minus), ..)| ((skipped + 1, minus), None, pos + 1));
+
+        next[pos] = cells.chain(skip).fold(None, |best, reading| match best {
+            Some(best) if best.0 <= reading.0 => Some(best),
+            _ => Some(reading),
+        });
+    }
+
+    let (cost, ..) = next[0]?;
+    let mut cells = Vec::new();
+    let mut pos = 0;
+    while pos < width {
+        let (_, cell, after) = next[pos]?;
+        cells.extend(cell);
+        pos = after;
+    }
+
+    Some((cost, cells))
+}
+
+/// Columns and characters row by row of the glyphs of every cell in a size and style, the digits
+/// and the dash first.
+fn glyphs(size: DigitSize, style: GlyphStyle) -> Vec<(Cell, usize, Vec<char>)> {
+    let segments = SEGMENTS
+        .iter()
+        .copied()
+        .chain(core::iter::once(Segments::NONE))
+        .chain(
+            (0..0x80)
+                .map(Segments::from_bits)
+                .filter(|segments| !segments.is_empty() && !SEGMENTS.contains(segments)),
+        );
+
+    let mut glyphs = Vec::new();
+    for minus in [false, true] {
+        for segments in segments.clone() {
+            for cell in [(minus, segments), (minus, segments | Segments::DP)] {
+                let mut text = String::new();
+                for row in 0..size.rows() {
+                    let _ = write_cell_row(&mut text, cell, size, style, row);
+                }
+                let chars = text.chars().collect::<Vec<_>>();
+                glyphs.push((cell, chars.len() / size.rows(), chars));
+            }
+        }
+    }
+
+    glyphs
+}
+
+/// Characters of the glyphs of a style, the same in every size.
+fn alphabet(style: GlyphStyle) -> Vec<char> {
+    let size = DigitSize::SMALL;
+    let mut text = String::from(" ");
+    for minus in [false, true] {
+        for bits in 0..=0xff {
+            for row in 0..size.rows() {
+                let _ = write_cell_row(
+                    &mut text,
+                    (minus, Segments::from_bits(bits)),
+                    size,
+                    style,
+                    row,
+                );
+            }
+        }
+    }
+
+    let mut alphabet = text.chars().collect::<Vec<_>>();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    alphabet
+}
+
+/// The characters, points and minus sign of the cells.
+fn decoded(cells: &[Cell]) -> Decoded {
+    let mut decoded = Decoded::default();
+    for (idx, &(minus, segments)) in cells.iter().enumerate() {
+        let digit = Segments::from_bits(segments.bits() & !Segments::DP.bits());
+        match SEGMENTS.iter().position(|&known| known == digit) {
+            Some(10) => decoded.string.push('-'),
+            Some(digit) => decoded.string.push(char::from(b'0' + digit as u8)),
+            None if digit.is_empty() => decoded.string.push(' '),
+            None => {
+                decoded.string.push('?');
+                decoded.unrecognized.push(idx);
+            }
+        }
+        if segments.contains(Segments::DP) {
+            decoded.points.push(idx);
+        }
+        decoded.negative |= minus;
+    }
+
+    decoded
+}
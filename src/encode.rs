@@ -0,0 +1,140 @@
+//! Segment bytes for seven segment display drivers.
+use crate::segments::str_segments;
+use crate::Segments;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Segment to bit mapping of a display driver.
+///
+/// Holds the bit position of the segments `a` to `g` and of the decimal point, and whether a lit
+/// segment is a cleared bit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{SegmentMap, Segments};
+/// // Segment `a` on bit 7 down to the decimal point on bit 0.
+/// let map = SegmentMap::new([7, 6, 5, 4, 3, 2, 1, 0]);
+///
+/// assert_eq!(map.encode(Segments::A | Segments::DP), 0b1000_0001);
+/// assert_eq!(map.active_low(true).encode(Segments::A), 0b0111_1111);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SegmentMap {
+    bits: [u8; 8],
+    active_low: bool,
+}
+
+impl SegmentMap {
+    /// MAX7219 and MAX7221 in no-decode mode, decimal point on bit 7, `a` on bit 6 to `g` on bit 0.
+    pub const MAX7219: SegmentMap = SegmentMap::new([6, 5, 4, 3, 2, 1, 0, 7]);
+    /// TM1637, `a` on bit 0 to `g` on bit 6, decimal point or colon on bit 7.
+    pub const TM1637: SegmentMap = SegmentMap::new([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// HT16K33 seven segment backpacks, low byte of a digit, `a` on bit 0 to the decimal point on
+    /// bit 7.
+    pub const HT16K33: SegmentMap = SegmentMap::new([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// 74HC595 shift register, outputs `Q0` to `Q7` wired to `a` to `g` and the decimal point of a
+    /// common cathode display.
+    pub const HC595_COMMON_CATHODE: SegmentMap = SegmentMap::new([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// 74HC595 shift register, outputs `Q0` to `Q7` wired to `a` to `g` and the decimal point of a
+    /// common anode display, a lit segment is a low output.
+    pub const HC595_COMMON_ANODE: SegmentMap =
+        SegmentMap::new([0, 1, 2, 3, 4, 5, 6, 7]).active_low(true);
+
+    /// Mapping from the bit positions of the segments `a` to `g` and the decimal point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bit position is greater than seven.
+    pub const fn new(bits: [u8; 8]) -> Self {
+        let mut idx = 0;
+        while idx < bits.len() {
+            assert!(bits[idx] < 8, "bit position out of range");
+            idx += 1;
+        }
+
+        SegmentMap {
+            bits,
+            active_low: false,
+        }
+    }
+
+    /// Sets whether a lit segment is a cleared bit.
+    pub const fn active_low(mut self, active_low: bool) -> Self {
+        self.active_low = active_low;
+        self
+    }
+
+    /// Bit positions of the segments `a` to `g` and the decimal point.
+    pub const fn bits(&self) -> [u8; 8] {
+        self.bits
+    }
+
+    /// Byte of the lit segments.
+    pub fn encode(&self, segments: Segments) -> u8 {
+        let byte = self
+            .bits
+            .iter()
+            .enumerate()
+            .filter(|(segment, _)| segments.bits() & 1 << segment != 0)
+            .fold(0, |byte, (_, &bit)| byte | 1 << bit);
+
+        if self.active_low {
+            !byte
+        } else {
+            byte
+        }
+    }
+}
+
+impl Default for SegmentMap {
+    fn default() -> Self {
+        SegmentMap::TM1637
+    }
+}
+
+/// Segment bytes of a string, one byte per digit.
+///
+/// Digits from zero to nine, dashes and spaces, a point sets the decimal point of the digit before.
+/// The bytes are in the order of the string, the first byte is the leftmost digit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_encode, SegmentMap};
+/// assert_eq!(sevseg_encode("12.5", &SegmentMap::TM1637), Some(vec![0x06, 0xdb, 0x6d]));
+/// assert_eq!(sevseg_encode("-1", &SegmentMap::MAX7219), Some(vec![0x01, 0x30]));
+/// assert_eq!(sevseg_encode("1x", &SegmentMap::MAX7219), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sevseg_encode(string: &str, map: &SegmentMap) -> Option<Vec<u8>> {
+    str_segments(string)
+        .map(|segments| segments.map(|segments| map.encode(segments)))
+        .collect()
+}
+
+/// Segment bytes of a string written to the start of a buffer, returns the number of digits.
+///
+/// Takes the same strings as [`sevseg_encode`]. Returns `None` if the string has any other
+/// character or the buffer is too small.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_encode_into, SegmentMap};
+/// let mut buf = [0; 8];
+///
+/// assert_eq!(sevseg_encode_into("12.5", &SegmentMap::TM1637, &mut buf), Some(3));
+/// assert_eq!(buf[..3], [0x06, 0xdb, 0x6d]);
+/// assert_eq!(sevseg_encode_into("123456789", &SegmentMap::TM1637, &mut buf), None);
+/// ```
+pub fn sevseg_encode_into(string: &str, map: &SegmentMap, buf: &mut [u8]) -> Option<usize> {
+    let mut len = 0;
+
+    for segments in str_segments(string) {
+        *buf.get_mut(len)? = map.encode(segments?);
+        len += 1;
+    }
+
+    Some(len)
+}
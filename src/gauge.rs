@@ -0,0 +1,104 @@
+//! Seven segment values inside a ring of ticks.
+use crate::bar::{lit_count, segment_char};
+use crate::render::GlyphStyle;
+use crate::{sevseg_number_with, BarDirection, NumberStyle};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Analog style gauge of a seven segment value inside a ring of tick segments.
+///
+/// The ring runs from the bottom of the left side over the top to the bottom of the right side,
+/// open at the bottom like the arc of a dial, and lights the fraction of its ticks the value has
+/// of the range. The value is shown by [`sevseg_number_with`] in the digits of the gauge.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::Gauge;
+/// let gauge = Gauge::new(0.0, 100.0).digits(2);
+///
+/// assert_eq!(gauge.render(42.0).unwrap().lines().collect::<Vec<_>>(), [
+///     "┏━━━━━╍╍╍╍╍╍╍╍┓",
+///     "┃ ╻   ╻ ╺━━━┓ ╏",
+///     "┃ ┃   ┃     ┃ ╏",
+///     "┃ ┗━━━┫ ┏━━━┛ ╏",
+///     "┃     ┃ ┃     ╏",
+///     "┃     ╹ ┗━━━╸ ╏",
+/// ]);
+/// assert_eq!(gauge.render(99.0).unwrap().lines().last(), Some("┃ ╺━━━┛ ╺━━━┛ ┃"));
+/// assert_eq!(gauge.render(100.5), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gauge {
+    min: f64,
+    max: f64,
+    digits: usize,
+    number_style: NumberStyle,
+    glyph_style: GlyphStyle,
+}
+
+impl Gauge {
+    /// Gauge of the values from `min` to `max` in four digits with the number style of
+    /// [`sevseg_number`](crate::sevseg_number).
+    pub fn new(min: f64, max: f64) -> Self {
+        Gauge {
+            min,
+            max,
+            digits: 4,
+            number_style: NumberStyle::new(),
+            glyph_style: GlyphStyle::Heavy,
+        }
+    }
+
+    /// Sets the number of digits of the value.
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Sets the style of the value.
+    pub fn number_style(mut self, number_style: NumberStyle) -> Self {
+        self.number_style = number_style;
+        self
+    }
+
+    /// Sets the characters the ring is drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// The gauge of a value, `None` if the value does not fit into the digits.
+    ///
+    /// Values outside the range light no or all ticks.
+    pub fn render(&self, value: f64) -> Option<String> {
+        let display = sevseg_number_with(&value, self.digits, self.number_style)?;
+        let lines = display.lines().collect::<Vec<_>>();
+        let rows = lines.len();
+        let width = lines.first().map_or(0, |line| line.chars().count()) + 2;
+
+        let lit = lit_count(value, self.min, self.max, 2 * rows + width);
+        let tick = |direction, idx| segment_char(direction, self.glyph_style, idx < lit);
+        let (left, right) = match self.glyph_style {
+            GlyphStyle::Heavy => ('┏', '┓'),
+            GlyphStyle::Light => ('┌', '┐'),
+            GlyphStyle::Ascii => ('+', '+'),
+        };
+
+        let mut text = String::new();
+        text.push(left);
+        text.extend((rows..rows + width).map(|idx| tick(BarDirection::Vertical, idx)));
+        text.push(right);
+        text.push('\n');
+        for (row, line) in lines.iter().enumerate() {
+            text.push(tick(BarDirection::Horizontal, rows - 1 - row));
+            text.push(' ');
+            text.push_str(line);
+            text.push(' ');
+            text.push(tick(BarDirection::Horizontal, rows + width + row));
+            text.push('\n');
+        }
+
+        Some(text)
+    }
+}
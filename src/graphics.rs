@@ -0,0 +1,207 @@
+//! Seven segment display for `embedded-graphics` draw targets.
+use crate::segments::str_segments;
+use crate::Segments;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+/// Size and colors of the digits of a [`SegmentDisplay`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::SegmentStyle;
+/// # use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// let style = SegmentStyle::new(Size::new(12, 21), BinaryColor::On)
+///     .segment_width(3)
+///     .digit_spacing(4)
+///     .off_color(BinaryColor::Off);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentStyle<C> {
+    digit_size: Size,
+    segment_width: u32,
+    digit_spacing: u32,
+    on_color: C,
+    off_color: Option<C>,
+}
+
+impl<C: PixelColor> SegmentStyle<C> {
+    /// Style of digits with the given size and color of lit segments.
+    ///
+    /// The segment width is a fifth of the digit width but at least one pixel, the digits are
+    /// spaced by two segment widths and unlit segments are not drawn.
+    pub fn new(digit_size: Size, on_color: C) -> Self {
+        let segment_width = (digit_size.width / 5).max(1);
+
+        SegmentStyle {
+            digit_size,
+            segment_width,
+            digit_spacing: segment_width * 2,
+            on_color,
+            off_color: None,
+        }
+    }
+
+    /// Sets the width of a segment.
+    pub fn segment_width(mut self, segment_width: u32) -> Self {
+        self.segment_width = segment_width;
+        self
+    }
+
+    /// Sets the space between two digits, the decimal point is drawn in the middle of it.
+    pub fn digit_spacing(mut self, digit_spacing: u32) -> Self {
+        self.digit_spacing = digit_spacing;
+        self
+    }
+
+    /// Sets the color of lit segments.
+    pub fn on_color(mut self, on_color: C) -> Self {
+        self.on_color = on_color;
+        self
+    }
+
+    /// Sets the color of unlit segments.
+    pub fn off_color(mut self, off_color: C) -> Self {
+        self.off_color = Some(off_color);
+        self
+    }
+
+    /// Area of one segment of a digit with the top left corner at `top_left`.
+    fn segment_area(&self, top_left: Point, segment: Segments) -> Rectangle {
+        let Size { width, height } = self.digit_size;
+        let seg = self.segment_width;
+        let mid = height.saturating_sub(seg) / 2;
+        let horizontal = Size::new(width.saturating_sub(seg * 2), seg);
+        let upper = Size::new(seg, mid.saturating_sub(seg));
+        let lower = Size::new(seg, height.saturating_sub(mid + seg * 2));
+        let right = width.saturating_sub(seg) as i32;
+        let (seg, mid) = (seg as i32, mid as i32);
+
+        let (offset, size) = match segment {
+            Segments::A => (Point::new(seg, 0), horizontal),
+            Segments::B => (Point::new(right, seg), upper),
+            Segments::C => (Point::new(right, mid + seg), lower),
+            Segments::D => (Point::new(seg, height as i32 - seg), horizontal),
+            Segments::E => (Point::new(0, mid + seg), lower),
+            Segments::F => (Point::new(0, seg), upper),
+            Segments::G => (Point::new(seg, mid), horizontal),
+            _ => (
+                Point::new(
+                    width as i32 + (self.digit_spacing as i32 - seg) / 2,
+                    height as i32 - seg,
+                ),
+                Size::new(seg as u32, seg as u32),
+            ),
+        };
+
+        Rectangle::new(top_left + offset, size)
+    }
+}
+
+/// Seven segment display of a string drawn on an `embedded-graphics` draw target.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{SegmentDisplay, SegmentStyle};
+/// # use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*};
+/// let style = SegmentStyle::new(Size::new(5, 7), BinaryColor::On).digit_spacing(1);
+/// let mut display = MockDisplay::new();
+///
+/// SegmentDisplay::new("7", Point::zero(), style)
+///     .unwrap()
+///     .draw(&mut display)
+///     .unwrap();
+///
+/// display.assert_pattern(&[
+///     " ### ",
+///     "    #",
+///     "    #",
+///     "     ",
+///     "    #",
+///     "    #",
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentDisplay<'a, C> {
+    text: &'a str,
+    position: Point,
+    style: SegmentStyle<C>,
+}
+
+impl<'a, C: PixelColor> SegmentDisplay<'a, C> {
+    /// Display of digits from zero to nine, dashes and spaces with the top left corner at
+    /// `position`, a point sets the decimal point of the digit before.
+    ///
+    /// Returns `None` if the text has any other character.
+    pub fn new(text: &'a str, position: Point, style: SegmentStyle<C>) -> Option<Self> {
+        if str_segments(text).any(|segments| segments.is_none()) {
+            return None;
+        }
+
+        Some(SegmentDisplay {
+            text,
+            position,
+            style,
+        })
+    }
+
+    fn digits(&self) -> impl Iterator<Item = Segments> + 'a {
+        str_segments(self.text).flatten()
+    }
+
+    fn pitch(&self) -> i32 {
+        (self.style.digit_size.width + self.style.digit_spacing) as i32
+    }
+}
+
+impl<C: PixelColor> Dimensions for SegmentDisplay<'_, C> {
+    fn bounding_box(&self) -> Rectangle {
+        let count = self.digits().count() as u32;
+        let mut width = (count * self.pitch() as u32).saturating_sub(self.style.digit_spacing);
+
+        // The decimal point of the last digit is drawn in the spacing after it.
+        let last = self.digits().last();
+        if last.is_some_and(|last| last.contains(Segments::DP) || self.style.off_color.is_some()) {
+            let point = self.style.segment_area(Point::zero(), Segments::DP);
+            let right = (count - 1) * self.pitch() as u32 + point.top_left.x as u32;
+            width = width.max(right + point.size.width);
+        }
+
+        Rectangle::new(
+            self.position,
+            Size::new(width, self.style.digit_size.height),
+        )
+    }
+}
+
+impl<C: PixelColor> Drawable for SegmentDisplay<'_, C> {
+    type Color = C;
+    /// Position of the next digit after the display.
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut position = self.position;
+
+        for segments in self.digits() {
+            for segment in Segments::ALL.into_iter().chain([Segments::DP]) {
+                let color = if segments.contains(segment) {
+                    Some(self.style.on_color)
+                } else {
+                    self.style.off_color
+                };
+
+                if let Some(color) = color {
+                    target.fill_solid(&self.style.segment_area(position, segment), color)?;
+                }
+            }
+
+            position.x += self.pitch();
+        }
+
+        Ok(position)
+    }
+}
@@ -0,0 +1,158 @@
+//! HTML output of the seven segment display.
+//!
+//! Every segment is an empty `<span>` with the class of the segment name and its state,
+//! `sevseg-on` or `sevseg-off`, so a page can style lit and unlit segments itself or use
+//! [`SEVSEG_CSS`].
+use crate::segments::SEGMENTS;
+use crate::{four_digits, Grouping, NumberStyle, Segments, SignPolicy};
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt::Write;
+use num::Bounded;
+
+/// Default style sheet for the HTML display, the digit height follows the font size.
+pub const SEVSEG_CSS: &str = "\
+.sevseg{display:inline-flex;gap:.3em;color:#e22}
+.sevseg-digit{position:relative;display:inline-block;width:1em;height:2em}
+.sevseg-sign{position:relative;display:inline-block;width:.5em;height:2em}
+.sevseg-digit>span,.sevseg-sign>span{position:absolute;background:currentColor;border-radius:.06em}
+.sevseg-off{opacity:.08}
+.sevseg-a,.sevseg-d,.sevseg-g{left:.12em;width:.76em;height:.12em}
+.sevseg-b,.sevseg-c,.sevseg-e,.sevseg-f{width:.12em;height:.82em}
+.sevseg-a{top:0}
+.sevseg-g{top:.94em}
+.sevseg-d{bottom:0}
+.sevseg-b,.sevseg-f{top:.12em}
+.sevseg-c,.sevseg-e{bottom:.12em}
+.sevseg-b,.sevseg-c{right:0}
+.sevseg-e,.sevseg-f{left:0}
+.sevseg-dp{right:-.24em;bottom:0;width:.14em;height:.14em;border-radius:50%}
+.sevseg-minus{left:0;top:.94em;width:.4em;height:.12em}
+.sevseg-gap{width:.2em}
+.sevseg-apostrophe{align-self:flex-start;width:.12em;height:.3em;background:currentColor}
+";
+
+/// Four digits seven segment display as HTML.
+///
+/// Takes the same strings as [`sevseg_four`](crate::sevseg_four).
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::sevseg_four_html;
+/// let html = sevseg_four_html("1").unwrap();
+///
+/// assert!(html.starts_with("<div class=\"sevseg\">\n<span class=\"sevseg-digit\">"));
+/// assert!(html.ends_with("<span class=\"sevseg-b sevseg-on\"></span>\
+///                         <span class=\"sevseg-c sevseg-on\"></span>\
+///                         <span class=\"sevseg-d sevseg-off\"></span>\
+///                         <span class=\"sevseg-e sevseg-off\"></span>\
+///                         <span class=\"sevseg-f sevseg-off\"></span>\
+///                         <span class=\"sevseg-g sevseg-off\"></span></span>\n</div>\n"));
+/// ```
+pub fn sevseg_four_html(string: &str) -> Option<String> {
+    let digit = four_digits(string)?;
+
+    Some(html_display(
+        false,
+        false,
+        digit.iter().map(|&idx| (false, SEGMENTS[idx])),
+        (&[], ""),
+    ))
+}
+
+/// Four digits seven segment display with decimal point as HTML.
+///
+/// Takes the same numbers as [`sevseg_four_dp`](crate::sevseg_four_dp), a negative number gets a
+/// `sevseg-sign` element in front of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::sevseg_four_dp_html;
+/// let html = sevseg_four_dp_html(-1.5_f32);
+///
+/// assert!(html.starts_with("<div class=\"sevseg\">\n\
+///                           <span class=\"sevseg-sign\"><span class=\"sevseg-minus sevseg-on\"></span></span>\n"));
+/// assert_eq!(html.matches("sevseg-dp sevseg-on").count(), 1);
+/// ```
+pub fn sevseg_four_dp_html<T>(n: T) -> String
+where
+    T: core::fmt::Display + Bounded,
+{
+    sevseg_four_dp_html_with(n, NumberStyle::new())
+}
+
+/// Four digits seven segment display with decimal point in a [`NumberStyle`] as HTML.
+///
+/// Takes the same numbers as [`sevseg_four_dp_with`](crate::sevseg_four_dp_with), the blank sign
+/// column of [`SignPolicy::Column`] is a `sevseg-sign` element with an unlit minus sign and the
+/// group separators of [`Grouping::Apostrophe`] and [`Grouping::Gap`] are `sevseg-apostrophe` and
+/// `sevseg-gap` elements.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four_dp_html_with, NumberStyle, SignPolicy};
+/// let html = sevseg_four_dp_html_with(1.5_f32, NumberStyle::new().sign(SignPolicy::Column));
+///
+/// assert!(html.starts_with("<div class=\"sevseg\">\n\
+///                           <span class=\"sevseg-sign\"><span class=\"sevseg-minus sevseg-off\"></span></span>\n"));
+/// ```
+pub fn sevseg_four_dp_html_with<T: core::fmt::Display>(n: T, style: NumberStyle) -> String {
+    let mut cells = style.four_cells(&n.to_string());
+    let groups = style.group_cells(&mut cells);
+    let blank_sign = style.sign == SignPolicy::Column && !cells[0].0;
+    let separator = match style.grouping {
+        Grouping::Apostrophe => "<span class=\"sevseg-apostrophe\"></span>\n",
+        _ => "<span class=\"sevseg-gap\"></span>\n",
+    };
+
+    html_display(true, blank_sign, cells.into_iter(), (&groups, separator))
+}
+
+/// The digits with a separator element after the digits of the group indexes.
+fn html_display(
+    dp: bool,
+    blank_sign: bool,
+    digits: impl Iterator<Item = (bool, Segments)>,
+    (groups, separator): (&[usize], &str),
+) -> String {
+    let mut html = String::from("<div class=\"sevseg\">\n");
+    if blank_sign {
+        html.push_str(
+            "<span class=\"sevseg-sign\"><span class=\"sevseg-minus sevseg-off\"></span></span>\n",
+        );
+    }
+
+    for (idx, (minus, segments)) in digits.enumerate() {
+        if minus {
+            html.push_str(
+                "<span class=\"sevseg-sign\"><span class=\"sevseg-minus sevseg-on\"></span></span>\n",
+            );
+        }
+
+        html.push_str("<span class=\"sevseg-digit\">");
+        let dp_segment = if dp { Some(Segments::DP) } else { None };
+        for segment in Segments::ALL.into_iter().chain(dp_segment) {
+            let state = if segments.contains(segment) {
+                "on"
+            } else {
+                "off"
+            };
+            let _ = write!(
+                html,
+                "<span class=\"sevseg-{} sevseg-{}\"></span>",
+                segment.name(),
+                state
+            );
+        }
+        html.push_str("</span>\n");
+        if groups.contains(&idx) {
+            html.push_str(separator);
+        }
+    }
+
+    html.push_str("</div>\n");
+    html
+}
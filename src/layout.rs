@@ -0,0 +1,530 @@
+//! Layout of many displays in one text block.
+use crate::render::{write_cells, GlyphStyle};
+use crate::{DigitSize, Segments};
+
+/// Lines drawn around a panel of a [`Dashboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Border {
+    /// Thin lines.
+    Light,
+    /// Thick lines.
+    Heavy,
+    /// Thin lines with rounded corners.
+    Rounded,
+    /// Double lines.
+    Double,
+}
+
+impl Border {
+    /// Top left, top right, bottom left and bottom right corner, horizontal and vertical line.
+    fn chars(self) -> [char; 6] {
+        match self {
+            Border::Light => ['┌', '┐', '└', '┘', '─', '│'],
+            Border::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+            Border::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            Border::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+        }
+    }
+}
+
+/// How a [`Dashboard`] places its panels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Arrangement {
+    /// Rows of a fixed number of columns, every column as wide as its widest panel.
+    Grid(usize),
+    /// Panels side by side, a new row when the next panel exceeds the width.
+    Flow(usize),
+}
+
+/// Where a [`Readout`] places its title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitlePosition {
+    /// Centered above the digits.
+    Above,
+    /// Centered below the digits.
+    Below,
+}
+
+/// How a [`Readout`] draws its unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitStyle {
+    /// Plain text beside the bottom line of the digits.
+    Text,
+    /// Seven segment glyphs as high as the digits of [`sevseg_four`](crate::sevseg_four).
+    ///
+    /// Falls back to text for a unit with a character without a seven segment form, see
+    /// [`Segments::from_unit_char`].
+    Segments,
+}
+
+/// A display with an optional title and a unit beside the digits.
+///
+/// Any text block can be the display, a readout converts into a `String` and so into a panel of
+/// a [`Dashboard`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_two, Readout, UnitStyle};
+/// let readout = Readout::new(sevseg_two("21").unwrap())
+///     .title("CPU 3")
+///     .unit("°C")
+///     .unit_style(UnitStyle::Segments)
+///     .render();
+///
+/// assert_eq!(readout.lines().collect::<Vec<_>>(), [
+///     "         CPU 3         ",
+///     "╺━━━┓     ╻ ┏━━━┓ ┏━━━╸",
+///     "    ┃     ┃ ┃   ┃ ┃    ",
+///     "┏━━━┛     ┃ ┗━━━┛ ┃    ",
+///     "┃         ┃       ┃    ",
+///     "┗━━━╸     ╹       ┗━━━╸",
+/// ]);
+/// ```
+///
+/// ```
+/// # use seven_seg::{sevseg_one, Readout, TitlePosition};
+/// let readout = Readout::new(sevseg_one("7").unwrap())
+///     .title("Load")
+///     .title_position(TitlePosition::Below)
+///     .unit("%")
+///     .render();
+///
+/// assert_eq!(readout.lines().collect::<Vec<_>>(), [
+///     "╺━━━┓  ",
+///     "    ┃  ",
+///     "    ┃  ",
+///     "    ┃  ",
+///     "    ╹ %",
+///     " Load  ",
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Readout {
+    display: String,
+    title: Option<String>,
+    title_position: TitlePosition,
+    unit: Option<String>,
+    unit_style: UnitStyle,
+}
+
+impl Readout {
+    /// Readout of a display without title and unit.
+    pub fn new<S: Into<String>>(display: S) -> Self {
+        Readout {
+            display: display.into(),
+            title: None,
+            title_position: TitlePosition::Above,
+            unit: None,
+            unit_style: UnitStyle::Text,
+        }
+    }
+
+    /// Sets the title, above the digits by default.
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the position of the title.
+    pub fn title_position(mut self, position: TitlePosition) -> Self {
+        self.title_position = position;
+        self
+    }
+
+    /// Sets the unit after the digits.
+    pub fn unit<S: Into<String>>(mut self, unit: S) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Sets how the unit is drawn, as text by default.
+    pub fn unit_style(mut self, style: UnitStyle) -> Self {
+        self.unit_style = style;
+        self
+    }
+
+    /// The composed text block, every line padded to the same width.
+    pub fn render(&self) -> String {
+        let mut block = Block::new(&self.display);
+
+        if let Some(unit) = &self.unit {
+            let unit = self.unit_block(unit, block.lines.len());
+            block = beside(block, unit);
+        }
+
+        if let Some(title) = &self.title {
+            let title = Block::new(title);
+            block = match self.title_position {
+                TitlePosition::Above => stack(title, block),
+                TitlePosition::Below => stack(block, title),
+            };
+        }
+
+        block.lines.iter().fold(String::new(), |mut text, line| {
+            pad(&mut text, line, block.width);
+            text.push('\n');
+            text
+        })
+    }
+
+    fn unit_block(&self, unit: &str, height: usize) -> Block {
+        if self.unit_style == UnitStyle::Segments {
+            let cells = unit
+                .chars()
+                .map(|chr| Segments::from_unit_char(chr).map(|segments| (false, segments)))
+                .collect::<Option<Vec<_>>>();
+
+            if let Some(cells) = cells {
+                let mut text = String::new();
+                if write_cells(
+                    &mut text,
+                    cells.into_iter(),
+                    DigitSize::NORMAL,
+                    GlyphStyle::Heavy,
+                )
+                .is_ok()
+                {
+                    return Block::new(&text);
+                }
+            }
+        }
+
+        let mut block = Block::new(unit);
+        let padding = height.saturating_sub(block.lines.len());
+        block
+            .lines
+            .splice(..0, std::iter::repeat_n(String::new(), padding));
+        block
+    }
+}
+
+impl From<Readout> for String {
+    fn from(readout: Readout) -> String {
+        readout.render()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Panel {
+    display: String,
+    label: Option<String>,
+}
+
+/// Displays arranged in a grid or flow layout, with labels, spacing and borders.
+///
+/// The panels are text blocks such as the output of [`sevseg_four`](crate::sevseg_four), a
+/// label is centered below its display. Escape sequences do not count to the width of a line.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_two, Border, Dashboard};
+/// let dashboard = Dashboard::grid(2)
+///     .border(Border::Light)
+///     .labeled_panel(sevseg_two("12").unwrap(), "CPU 0")
+///     .labeled_panel(sevseg_two("7").unwrap(), "CPU 1")
+///     .render();
+///
+/// assert_eq!(dashboard.lines().collect::<Vec<_>>(), [
+///     "┌───────────┐ ┌───────────┐",
+///     "│    ╻ ╺━━━┓│ │┏━━━┓ ╺━━━┓│",
+///     "│    ┃     ┃│ │┃   ┃     ┃│",
+///     "│    ┃ ┏━━━┛│ │┃   ┃     ┃│",
+///     "│    ┃ ┃    │ │┃   ┃     ┃│",
+///     "│    ╹ ┗━━━╸│ │┗━━━┛     ╹│",
+///     "└───────────┘ └───────────┘",
+///     "    CPU 0         CPU 1    ",
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dashboard {
+    panels: Vec<Panel>,
+    arrangement: Arrangement,
+    column_spacing: usize,
+    row_spacing: usize,
+    border: Option<Border>,
+}
+
+impl Dashboard {
+    /// Dashboard of rows with `columns` panels.
+    pub fn grid(columns: usize) -> Self {
+        Dashboard::new(Arrangement::Grid(columns.max(1)))
+    }
+
+    /// Dashboard that fills rows up to `width` columns.
+    pub fn flow(width: usize) -> Self {
+        Dashboard::new(Arrangement::Flow(width))
+    }
+
+    fn new(arrangement: Arrangement) -> Self {
+        Dashboard {
+            panels: Vec::new(),
+            arrangement,
+            column_spacing: 1,
+            row_spacing: 0,
+            border: None,
+        }
+    }
+
+    /// Sets the blank columns between panels, one by default.
+    pub fn column_spacing(mut self, spacing: usize) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the blank lines between rows of panels, none by default.
+    pub fn row_spacing(mut self, spacing: usize) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the border drawn around every display.
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Adds a display.
+    pub fn panel<S: Into<String>>(mut self, display: S) -> Self {
+        self.push(display, None::<String>);
+        self
+    }
+
+    /// Adds a display with a label below it.
+    pub fn labeled_panel<S: Into<String>, L: Into<String>>(mut self, display: S, label: L) -> Self {
+        self.push(display, Some(label));
+        self
+    }
+
+    /// Adds a display with an optional label below it.
+    pub fn push<S: Into<String>, L: Into<String>>(&mut self, display: S, label: Option<L>) {
+        self.panels.push(Panel {
+            display: display.into(),
+            label: label.map(Into::into),
+        });
+    }
+
+    /// Removes all panels and keeps the layout.
+    pub fn clear(&mut self) {
+        self.panels.clear();
+    }
+
+    /// The composed text block, every line padded to the same width.
+    pub fn render(&self) -> String {
+        let blocks = self
+            .panels
+            .iter()
+            .map(|panel| self.panel_block(panel))
+            .collect::<Vec<_>>();
+
+        let mut rows: Vec<Vec<(Block, usize)>> = Vec::new();
+        match self.arrangement {
+            Arrangement::Grid(columns) => {
+                let mut widths = vec![0; columns];
+                for (idx, block) in blocks.iter().enumerate() {
+                    widths[idx % columns] = widths[idx % columns].max(block.width);
+                }
+                for (idx, block) in blocks.into_iter().enumerate() {
+                    if idx % columns == 0 {
+                        rows.push(Vec::new());
+                    }
+                    rows.last_mut()
+                        .unwrap()
+                        .push((block, widths[idx % columns]));
+                }
+            }
+            Arrangement::Flow(width) => {
+                let mut row_width = 0;
+                for block in blocks {
+                    let block_width = block.width;
+                    let needed = row_width + self.column_spacing + block_width;
+                    match rows.last_mut() {
+                        Some(row) if needed <= width => {
+                            row_width = needed;
+                            row.push((block, block_width));
+                        }
+                        _ => {
+                            row_width = block_width;
+                            rows.push(vec![(block, block_width)]);
+                        }
+                    }
+                }
+            }
+        }
+
+        let lines = self.compose(&rows);
+        let width = lines
+            .iter()
+            .map(|line| visible_width(line))
+            .max()
+            .unwrap_or(0);
+
+        lines.into_iter().fold(String::new(), |mut text, line| {
+            pad(&mut text, &line, width);
+            text.push('\n');
+            text
+        })
+    }
+
+    fn compose(&self, rows: &[Vec<(Block, usize)>]) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for (row_idx, row) in rows.iter().enumerate() {
+            if row_idx > 0 {
+                lines.extend(std::iter::repeat_n(String::new(), self.row_spacing));
+            }
+
+            let height = row
+                .iter()
+                .map(|(block, _)| block.lines.len())
+                .max()
+                .unwrap_or(0);
+            for line_idx in 0..height {
+                let mut line = String::new();
+                for (col_idx, (block, width)) in row.iter().enumerate() {
+                    if col_idx > 0 {
+                        line.extend(std::iter::repeat_n(' ', self.column_spacing));
+                    }
+                    pad(
+                        &mut line,
+                        block.lines.get(line_idx).map_or("", String::as_str),
+                        *width,
+                    );
+                }
+                lines.push(line);
+            }
+        }
+
+        lines
+    }
+
+    fn panel_block(&self, panel: &Panel) -> Block {
+        let mut display = Block::new(&panel.display);
+
+        if let Some(border) = self.border {
+            let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] =
+                border.chars();
+            let line = |left, right| {
+                let mut line = String::from(left);
+                line.extend(std::iter::repeat_n(horizontal, display.width));
+                line.push(right);
+                line
+            };
+
+            let mut lines = vec![line(top_left, top_right)];
+            for content in &display.lines {
+                let mut framed = String::from(vertical);
+                pad(&mut framed, content, display.width);
+                framed.push(vertical);
+                lines.push(framed);
+            }
+            lines.push(line(bottom_left, bottom_right));
+            display = Block {
+                lines,
+                width: display.width + 2,
+            };
+        }
+
+        match &panel.label {
+            Some(label) => stack(display, Block::new(label)),
+            None => display,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Block {
+    lines: Vec<String>,
+    width: usize,
+}
+
+impl Block {
+    fn new(text: &str) -> Self {
+        let lines = text.lines().map(String::from).collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| visible_width(line))
+            .max()
+            .unwrap_or(0);
+
+        Block { lines, width }
+    }
+}
+
+/// The blocks one below the other, both centered.
+fn stack(top: Block, bottom: Block) -> Block {
+    let width = top.width.max(bottom.width);
+    let indent = |block: &Block| " ".repeat((width - block.width) / 2);
+    let (top_indent, bottom_indent) = (indent(&top), indent(&bottom));
+
+    let lines = top
+        .lines
+        .iter()
+        .map(|line| format!("{}{}", top_indent, line))
+        .chain(
+            bottom
+                .lines
+                .iter()
+                .map(|line| format!("{}{}", bottom_indent, line)),
+        )
+        .collect();
+
+    Block { lines, width }
+}
+
+/// The blocks side by side separated by a space, their bottom lines aligned.
+fn beside(left: Block, right: Block) -> Block {
+    let height = left.lines.len().max(right.lines.len());
+    let line = |block: &Block, idx: usize| {
+        (idx + block.lines.len())
+            .checked_sub(height)
+            .map_or(String::new(), |idx| block.lines[idx].clone())
+    };
+
+    let lines = (0..height)
+        .map(|idx| {
+            let mut line_text = String::new();
+            pad(&mut line_text, &line(&left, idx), left.width);
+            line_text.push(' ');
+            line_text.push_str(&line(&right, idx));
+            line_text
+        })
+        .collect();
+
+    Block {
+        lines,
+        width: left.width + 1 + right.width,
+    }
+}
+
+/// Appends the line and spaces up to the width.
+fn pad(text: &mut String, line: &str, width: usize) {
+    text.push_str(line);
+    text.extend(std::iter::repeat_n(
+        ' ',
+        width.saturating_sub(visible_width(line)),
+    ));
+}
+
+/// Characters of a line without escape sequences.
+pub(crate) fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut chars = line.chars();
+
+    while let Some(chr) = chars.next() {
+        if chr == '\x1b' {
+            if chars.next() == Some('[') {
+                for chr in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&chr) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+
+    width
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(
     missing_docs,
@@ -23,9 +24,112 @@
 //!                           ┗━━━┛ ┗━━━┛ ┗━━━╸ ╺━━━┛\n"
 //! );
 //! ```
+//!
+//! # Features
+//!
+//! Without default features the crate is `no_std`, it maps characters to [`Segments`], encodes
+//! them for display drivers and renders them into caller provided buffers with
+//! [`sevseg_render`].
+//!
+//! - `alloc` adds the functions returning `String` or `Vec` that do not need `std` and the
+//!   scrolling [`Marquee`], animated [`Transition`], busy [`Spinner`], blinking
+//!   [`AttributedDigits`], the [`BarGraph`] beside the digits, the [`Gauge`] around them, values
+//!   scaled with SI prefixes by [`sevseg_si`], in scientific notation by [`sevseg_sci`], numbers
+//!   of any size, such as `BigInt` and `Ratio`, by [`sevseg_number`] and addresses, dates and
+//!   versions with a point on any digit by [`sevseg_points`].
+//! - `std`, enabled by default, adds `alloc`, the displays of one to four digits, the
+//!   [`Dashboard`] and [`Readout`] layouts and the [`Sparkline`] history beneath a display.
+//! - `embedded-graphics` adds [`SegmentDisplay`] to draw on `embedded-graphics` targets.
+//! - `ratatui` adds the [`SevenSegment`] widget.
+//! - `crossterm` adds [`LiveDisplay`] to redraw only the changed cells of a terminal display.
+//! - `cli` builds the `sevseg` command line program.
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 use cattocol::{by_four_lines, by_lines, by_three_lines};
+#[cfg(feature = "std")]
 use num::Bounded;
 
+#[cfg(feature = "alloc")]
+mod attributes;
+#[cfg(feature = "alloc")]
+mod bar;
+#[cfg(feature = "alloc")]
+mod decimal;
+#[cfg(feature = "alloc")]
+mod decode;
+mod encode;
+#[cfg(feature = "alloc")]
+mod gauge;
+#[cfg(feature = "embedded-graphics")]
+mod graphics;
+#[cfg(feature = "alloc")]
+mod html;
+#[cfg(feature = "std")]
+mod layout;
+#[cfg(feature = "crossterm")]
+mod live;
+#[cfg(feature = "alloc")]
+mod marquee;
+#[cfg(feature = "alloc")]
+mod number;
+#[cfg(feature = "alloc")]
+mod points;
+mod render;
+#[cfg(feature = "alloc")]
+mod scale;
+mod segments;
+#[cfg(feature = "std")]
+mod sparkline;
+#[cfg(feature = "alloc")]
+mod spinner;
+#[cfg(feature = "alloc")]
+mod transition;
+#[cfg(feature = "ratatui")]
+mod widget;
+
+#[cfg(feature = "alloc")]
+pub use attributes::{AttributedDigits, Attributes};
+#[cfg(feature = "alloc")]
+pub use bar::{BarDirection, BarGraph};
+#[cfg(feature = "alloc")]
+pub use decimal::{sevseg_number, sevseg_number_with, DecimalDigits};
+#[cfg(feature = "alloc")]
+pub use decode::{sevseg_decode, Decoded};
+#[cfg(feature = "alloc")]
+pub use encode::sevseg_encode;
+pub use encode::{sevseg_encode_into, SegmentMap};
+#[cfg(feature = "alloc")]
+pub use gauge::Gauge;
+#[cfg(feature = "embedded-graphics")]
+pub use graphics::{SegmentDisplay, SegmentStyle};
+#[cfg(feature = "alloc")]
+pub use html::{sevseg_four_dp_html, sevseg_four_dp_html_with, sevseg_four_html, SEVSEG_CSS};
+#[cfg(feature = "std")]
+pub use layout::{Border, Dashboard, Readout, TitlePosition, UnitStyle};
+#[cfg(feature = "crossterm")]
+pub use live::{sevseg_frame_size, LiveDisplay};
+#[cfg(feature = "alloc")]
+pub use marquee::{Marquee, ScrollDirection};
+#[cfg(feature = "alloc")]
+pub use number::{Grouping, NonFinite, NumberStyle, SignPolicy};
+#[cfg(feature = "alloc")]
+pub use points::sevseg_points;
+pub use render::{sevseg_render, sevseg_render_with, DigitSize, GlyphStyle};
+#[cfg(feature = "alloc")]
+pub use scale::{sevseg_sci, sevseg_sci_with, sevseg_si, sevseg_si_with, PrefixStyle};
+pub use segments::Segments;
+#[cfg(feature = "std")]
+pub use sparkline::Sparkline;
+#[cfg(feature = "alloc")]
+pub use spinner::{Spinner, SpinnerKind};
+#[cfg(feature = "alloc")]
+pub use transition::{Transition, TransitionEffect};
+#[cfg(feature = "ratatui")]
+pub use widget::SevenSegment;
+
+#[cfg(feature = "std")]
 #[rustfmt::skip]
 const SEVEN_SEG: [&str; 11] = [
     "┏━━━┓\n┃   ┃\n┃   ┃\n┃   ┃\n┗━━━┛\n",
@@ -41,6 +145,7 @@ const SEVEN_SEG: [&str; 11] = [
     "     \n     \n╺━━━╸\n     \n     \n",
 ];
 
+#[cfg(feature = "std")]
 #[rustfmt::skip]
 const SEVEN_SEG_DP: [&str; 40] = [
     "┏━━━┓\n┃   ┃\n┃   ┃\n┃   ┃\n┗━━━┛\n",
@@ -104,6 +209,7 @@ const SEVEN_SEG_DP: [&str; 40] = [
 ///                          ┗━━━┛\n"
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn sevseg_one(string: &str) -> Option<String> {
     let digit = if 1 == string.chars().count() {
@@ -148,6 +254,7 @@ pub fn sevseg_one(string: &str) -> Option<String> {
 ///                          ┗━━━┛      \n"
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn sevseg_two(string: &str) -> Option<String> {
     let len = string.chars().count();
@@ -216,6 +323,7 @@ pub fn sevseg_two(string: &str) -> Option<String> {
 ///                            ┗━━━┛ ┗━━━┛ ┗━━━╸\n"
 /// );
 /// ```
+#[cfg(feature = "std")]
 pub fn sevseg_three(string: &str) -> Option<String> {
     let len = string.chars().count();
     let first_digit = if len >= 1 { &string[0..1] } else { "" };
@@ -313,111 +421,16 @@ pub fn sevseg_three(string: &str) -> Option<String> {
 ///                           ┗━━━┛ ┗━━━┛ ┗━━━╸ ╺━━━┛\n"
 /// );
 /// ```
+#[cfg(feature = "std")]
 pub fn sevseg_four(string: &str) -> Option<String> {
-    let len = string.chars().count();
-    let first_digit = if len >= 1 { &string[0..1] } else { "" };
-    let second_digit = if len >= 2 { &string[1..2] } else { "" };
-    let third_digit = if len >= 3 { &string[2..3] } else { "" };
-    let fourth_digit = if len == 4 { &string[3..4] } else { "" };
-
-    let digit: (usize, usize, usize, usize) = if 4 == len {
-        (
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = third_digit.parse() {
-                digit
-            } else if third_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = fourth_digit.parse() {
-                digit
-            } else if fourth_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 3 == len {
-        (
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = third_digit.parse() {
-                digit
-            } else if third_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 2 == len {
-        (
-            0,
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 1 == len {
-        (
-            0,
-            0,
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else {
-        return None;
-    };
+    let digit = four_digits(string)?;
 
     Some(
         by_four_lines(
-            SEVEN_SEG[digit.0],
-            SEVEN_SEG[digit.1],
-            SEVEN_SEG[digit.2],
-            SEVEN_SEG[digit.3],
+            SEVEN_SEG[digit[0]],
+            SEVEN_SEG[digit[1]],
+            SEVEN_SEG[digit[2]],
+            SEVEN_SEG[digit[3]],
         )
         .collect::<String>(),
     )
@@ -438,110 +451,15 @@ pub fn sevseg_four(string: &str) -> Option<String> {
 ///                           ┗━━━┛ ┗━━━┛ ┗━━━╸ ╺━━━┛\n"
 /// );
 /// ```
+#[cfg(feature = "std")]
 pub fn sevseg_four_iter(string: &str) -> Option<impl Iterator<Item = &str>> {
-    let len = string.chars().count();
-    let first_digit = if len >= 1 { &string[0..1] } else { "" };
-    let second_digit = if len >= 2 { &string[1..2] } else { "" };
-    let third_digit = if len >= 3 { &string[2..3] } else { "" };
-    let fourth_digit = if len == 4 { &string[3..4] } else { "" };
-
-    let digit: (usize, usize, usize, usize) = if 4 == len {
-        (
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = third_digit.parse() {
-                digit
-            } else if third_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = fourth_digit.parse() {
-                digit
-            } else if fourth_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 3 == len {
-        (
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = third_digit.parse() {
-                digit
-            } else if third_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 2 == len {
-        (
-            0,
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-            if let Ok(digit) = second_digit.parse() {
-                digit
-            } else if second_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else if 1 == len {
-        (
-            0,
-            0,
-            0,
-            if let Ok(digit) = first_digit.parse() {
-                digit
-            } else if first_digit == "-" {
-                10
-            } else {
-                return None;
-            },
-        )
-    } else {
-        return None;
-    };
+    let digit = four_digits(string)?;
 
     Some(by_four_lines(
-        SEVEN_SEG[digit.0],
-        SEVEN_SEG[digit.1],
-        SEVEN_SEG[digit.2],
-        SEVEN_SEG[digit.3],
+        SEVEN_SEG[digit[0]],
+        SEVEN_SEG[digit[1]],
+        SEVEN_SEG[digit[2]],
+        SEVEN_SEG[digit[3]],
     ))
 }
 
@@ -572,29 +490,127 @@ pub fn sevseg_four_iter(string: &str) -> Option<impl Iterator<Item = &str>> {
 ///                           ┗━━━┛ ┗━━━┛ ┗━━━╸ ╺━━━┛\n"
 /// );
 /// ```
+///
+/// A NaN or an infinite float is shown as `nAn`, `InF` or `-InF`, [`sevseg_four_dp_with`] shows
+/// them and the minus sign in other styles.
+///
+/// ```
+/// # use seven_seg::sevseg_four_dp;
+/// let four_digits = sevseg_four_dp(f32::NAN);
+///
+/// assert_eq!(four_digits.lines().collect::<Vec<_>>(), [
+///     "            ┏━━━┓      ",
+///     "            ┃   ┃      ",
+///     "      ┏━━━┓ ┣━━━┫ ┏━━━┓",
+///     "      ┃   ┃ ┃   ┃ ┃   ┃",
+///     "      ╹   ╹ ╹   ╹ ╹   ╹",
+/// ]);
+/// ```
+#[cfg(feature = "std")]
+#[inline]
 pub fn sevseg_four_dp<T>(n: T) -> String
 where
     T: std::fmt::Display + Bounded,
 {
+    sevseg_four_dp_with(n, NumberStyle::new())
+}
+
+/// Four digits seven segment digital display with decimal point in a [`NumberStyle`], with the
+/// minus sign drawn by its [`SignPolicy`], the integer digits grouped by its [`Grouping`] and a
+/// NaN or an infinite float shown as its [`NonFinite`] representation.
+///
+/// Takes anything formatted with `Display`, such as numbers of other locales with the decimal
+/// separator set by [`NumberStyle::point`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four_dp, sevseg_four_dp_with, NonFinite, NumberStyle, SignPolicy};
+/// let style = NumberStyle::new().non_finite(NonFinite::Error);
+///
+/// assert_eq!(
+///     sevseg_four_dp_with(f64::NEG_INFINITY, style),
+///     sevseg_four_dp_with(f64::NAN, style)
+/// );
+/// assert_eq!(sevseg_four_dp_with(-1.5, style), sevseg_four_dp(-1.5));
+///
+/// let style = NumberStyle::new().sign(SignPolicy::Column);
+/// let widths = [-1.5, 1.5].map(|n| sevseg_four_dp_with(n, style).lines().next().unwrap().len());
+/// assert_eq!(widths[0], widths[1]);
+/// ```
+#[cfg(feature = "std")]
+pub fn sevseg_four_dp_with<T: std::fmt::Display>(n: T, style: NumberStyle) -> String {
     let string = n.to_string();
-    let mut arr = [if &string[..1] == "-" { 20 } else { 0 }, 0, 0, 0];
-    let iter = string.chars();
-    let mut len = iter.clone().count();
-    let dp_pos = if let Some(pos) = string.find('.') { pos - 1 } else { len -= 1; usize::MAX };
-    #[allow(clippy::if_same_then_else)]
-    for (i, digit) in iter.enumerate()
-        .filter_map(|idx_chr| idx_chr.1.to_digit(10)
-            .map(|digit| 
-                if idx_chr.0 == dp_pos {
-                    10
-                } else if idx_chr.0 == len {
-                    10
-                } else { 0 } + digit as usize
-            )
-        ).enumerate().take(4){
-
-        arr[i] += digit;
+    if style.sign != SignPolicy::Merged
+        || style.grouping != Grouping::None
+        || style.non_finite.cells(&string).is_some()
+    {
+        let mut cells = style.four_cells(&string);
+        let groups = style.group_cells(&mut cells);
+        let mut display = String::new();
+        let _ = number::write_number_cells(
+            &mut display,
+            &cells,
+            &groups,
+            DigitSize::NORMAL,
+            GlyphStyle::Heavy,
+            style,
+        );
+        return display;
     }
 
+    let arr = four_dp_digits(string, style.point);
+
     by_four_lines( SEVEN_SEG_DP[arr[0]], SEVEN_SEG_DP[arr[1]], SEVEN_SEG_DP[arr[2]], SEVEN_SEG_DP[arr[3]]).collect::<String>()
 }
+
+// Indexes into `SEVEN_SEG` of a string of one to four digits or dashes, left padded with zeros.
+#[cfg(feature = "alloc")]
+fn four_digits(string: &str) -> Option<[usize; 4]> {
+    let len = string.chars().count();
+    if !(1..=4).contains(&len) {
+        return None;
+    }
+
+    let mut digit = [0; 4];
+    for (idx, chr) in digit[4 - len..].iter_mut().zip(string.chars()) {
+        *idx = match chr {
+            '0'..='9' => chr.to_digit(10)? as usize,
+            '-' => 10,
+            _ => return None,
+        };
+    }
+
+    Some(digit)
+}
+
+// Indexes into `SEVEN_SEG_DP`: `+ 10` decimal point, `+ 20` minus sign. The point is the decimal
+// separator of the formatted number, other characters than digits are skipped.
+#[cfg(feature = "alloc")]
+fn four_dp_digits<T: core::fmt::Display>(n: T, point: char) -> [usize; 4] {
+    use alloc::string::ToString;
+
+    let mut string = n.to_string();
+    // A leading separator has an implied zero before it, like `.5` for `0.5`.
+    let sign = usize::from(string.starts_with('-'));
+    if string[sign..].starts_with(point) {
+        string.insert(sign, '0');
+    }
+    let mut arr = [if sign == 1 { 20 } else { 0 }, 0, 0, 0];
+
+    // The digit before the separator carries the point, or the last digit of a number without
+    // one. No digit carries it in an empty string.
+    let point_idx = match string.chars().position(|chr| chr == point) {
+        Some(pos) => pos.checked_sub(1),
+        None => string.chars().count().checked_sub(1),
+    };
+    let digits = string.chars().enumerate().filter_map(|(idx, chr)| {
+        let digit = chr.to_digit(10)? as usize;
+        Some(digit + if Some(idx) == point_idx { 10 } else { 0 })
+    });
+    for (slot, digit) in arr.iter_mut().zip(digits) {
+        *slot += digit;
+    }
+
+    arr
+}
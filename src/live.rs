@@ -0,0 +1,242 @@
+//! Live terminal display that redraws only changed cells.
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Print, ResetColor};
+use std::io::{self, Write};
+
+/// One terminal cell, a character and the SGR escape sequences in effect for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    chr: char,
+    style: String,
+}
+
+impl Cell {
+    fn blank() -> Self {
+        Cell {
+            chr: ' ',
+            style: String::new(),
+        }
+    }
+}
+
+/// Live display of frames of text, such as composed seven segment displays.
+///
+/// Remembers the previous frame and writes only the cells that changed, each run of changed cells
+/// after a cursor move. Color and attribute escape sequences in a frame are kept per cell, other
+/// escape sequences are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, LiveDisplay};
+/// let mut live = LiveDisplay::new(Vec::new());
+///
+/// live.draw(&sevseg_four("8888").unwrap()).unwrap();
+/// let first = live.get_ref().len();
+/// live.draw(&sevseg_four("8889").unwrap()).unwrap();
+///
+/// // Only the lower left segment of the last digit is cleared.
+/// assert_eq!(
+///     &live.get_ref()[first..],
+///     "\x1b[3;19H┗\x1b[4;19H \x1b[5;19H╺".as_bytes()
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LiveDisplay<W: Write> {
+    out: W,
+    column: u16,
+    row: u16,
+    previous: Option<Vec<Vec<Cell>>>,
+}
+
+impl<W: Write> LiveDisplay<W> {
+    /// Live display writing to `out` with the top left corner of the frames in the top left
+    /// corner of the terminal.
+    pub fn new(out: W) -> Self {
+        LiveDisplay {
+            out,
+            column: 0,
+            row: 0,
+            previous: None,
+        }
+    }
+
+    /// Sets the zero based terminal column and row of the top left corner of the frames.
+    pub fn origin(mut self, column: u16, row: u16) -> Self {
+        self.column = column;
+        self.row = row;
+        self
+    }
+
+    /// Moves the top left corner of the frames to the zero based terminal column and row.
+    ///
+    /// Forgets the previous frame like [`invalidate`](Self::invalidate), the cells at the old
+    /// origin are left as they are.
+    pub fn set_origin(&mut self, column: u16, row: u16) {
+        self.column = column;
+        self.row = row;
+        self.invalidate();
+    }
+
+    /// Forgets the previous frame, the next frame is written completely.
+    ///
+    /// Call it after the screen was cleared or resized.
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
+
+    /// Writes the cells of the frame that differ from the previous frame and flushes the output.
+    ///
+    /// Cells of the previous frame outside of the new frame are cleared.
+    pub fn draw(&mut self, frame: &str) -> io::Result<()> {
+        let cells = frame_cells(frame);
+        let blank = Cell::blank();
+        let previous = self.previous.take();
+        let rows = cells.len().max(previous.as_ref().map_or(0, Vec::len));
+
+        for y in 0..rows {
+            let new_row = cells.get(y).map_or(&[][..], Vec::as_slice);
+            let old_row = previous.as_ref().and_then(|previous| previous.get(y));
+            let width = new_row.len().max(old_row.map_or(0, Vec::len));
+            let mut x = 0;
+
+            while x < width {
+                let changed = |x: usize| match previous {
+                    Some(_) => {
+                        let old = old_row.and_then(|old_row| old_row.get(x));
+                        new_row.get(x).unwrap_or(&blank) != old.unwrap_or(&blank)
+                    }
+                    None => x < new_row.len(),
+                };
+                if !changed(x) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < width && changed(x) {
+                    x += 1;
+                }
+                self.write_run(
+                    start,
+                    y,
+                    (start..x).map(|x| new_row.get(x).unwrap_or(&blank)),
+                )?;
+            }
+        }
+
+        self.previous = Some(cells);
+        self.out.flush()
+    }
+
+    /// Reference to the output.
+    pub fn get_ref(&self) -> &W {
+        &self.out
+    }
+
+    /// Mutable reference to the output.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    /// Returns the output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_run<'a, I>(&mut self, x: usize, y: usize, cells: I) -> io::Result<()>
+    where
+        I: Iterator<Item = &'a Cell>,
+    {
+        let column = self
+            .column
+            .saturating_add(u16::try_from(x).unwrap_or(u16::MAX));
+        let row = self
+            .row
+            .saturating_add(u16::try_from(y).unwrap_or(u16::MAX));
+        queue!(self.out, MoveTo(column, row))?;
+
+        let mut style = "";
+        for cell in cells {
+            if cell.style != style {
+                if !style.is_empty() {
+                    queue!(self.out, ResetColor)?;
+                }
+                queue!(self.out, Print(&cell.style))?;
+                style = &cell.style;
+            }
+            queue!(self.out, Print(cell.chr))?;
+        }
+
+        if !style.is_empty() {
+            queue!(self.out, ResetColor)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Columns and rows a frame takes on the terminal when drawn by a [`LiveDisplay`], without its
+/// escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, sevseg_frame_size};
+/// assert_eq!(sevseg_frame_size(&sevseg_four("8023").unwrap()), (23, 5));
+/// assert_eq!(sevseg_frame_size("\x1b[31mab\x1b[0mc\n"), (3, 1));
+/// ```
+pub fn sevseg_frame_size(frame: &str) -> (usize, usize) {
+    let cells = frame_cells(frame);
+    (cells.iter().map(Vec::len).max().unwrap_or(0), cells.len())
+}
+
+/// Lines of cells of a frame, SGR sequences stay with the cells after them.
+fn frame_cells(frame: &str) -> Vec<Vec<Cell>> {
+    let mut rows = vec![Vec::new()];
+    let mut style = String::new();
+    let mut chars = frame.chars().peekable();
+
+    while let Some(chr) = chars.next() {
+        match chr {
+            '\n' => rows.push(Vec::new()),
+            '\r' => {}
+            '\x1b' => {
+                if chars.next_if_eq(&'[').is_none() {
+                    chars.next();
+                    continue;
+                }
+
+                let mut params = String::new();
+                let end = loop {
+                    match chars.next() {
+                        Some(chr @ '\x40'..='\x7e') => break Some(chr),
+                        Some(chr) => params.push(chr),
+                        None => break None,
+                    }
+                };
+
+                if end == Some('m') {
+                    if params.is_empty() || params == "0" {
+                        style.clear();
+                    } else {
+                        style.push_str("\x1b[");
+                        style.push_str(&params);
+                        style.push('m');
+                    }
+                }
+            }
+            chr => rows.last_mut().unwrap().push(Cell {
+                chr,
+                style: style.clone(),
+            }),
+        }
+    }
+
+    if rows.last().is_some_and(Vec::is_empty) {
+        rows.pop();
+    }
+
+    rows
+}
@@ -0,0 +1,169 @@
+//! Text scrolling through a display of fixed width.
+use crate::render::{write_cells, GlyphStyle};
+use crate::segments::text_segments;
+use crate::{DigitSize, Segments};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Direction the text of a [`Marquee`] moves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+    /// The text enters on the right and moves to the left.
+    Left,
+    /// The text enters on the left and moves to the right.
+    Right,
+}
+
+/// Iterator of the rendered frames of a text scrolling through a display.
+///
+/// The text can have digits, dashes, spaces, points, the degree sign and the letters of
+/// [`Segments::from_unit_char`]. Without wraparound the text scrolls in from a blank display and
+/// the frames end when it has left the display. With wraparound the frames never end and the text
+/// follows itself after a gap of blank digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::Marquee;
+/// let frames = Marquee::new("12", 2).unwrap().collect::<Vec<_>>();
+///
+/// assert_eq!(frames.len(), 4);
+/// assert_eq!(frames[0], "           \n".repeat(5));
+/// assert_eq!(frames[1], "          ╻\n          ┃\n          ┃\n          ┃\n          ╹\n");
+/// assert_eq!(frames[2], "    ╻ ╺━━━┓\n    ┃     ┃\n    ┃ ┏━━━┛\n    ┃ ┃    \n    ╹ ┗━━━╸\n");
+/// ```
+///
+/// ```
+/// # use seven_seg::{sevseg_four, Marquee};
+/// let mut marquee = Marquee::new("0123456", 4).unwrap().gap(2).wraparound(true);
+///
+/// assert_eq!(marquee.next(), sevseg_four("0123"));
+/// assert_eq!(marquee.nth(8), sevseg_four("0123"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marquee {
+    digits: Vec<Segments>,
+    width: usize,
+    direction: ScrollDirection,
+    gap: usize,
+    wraparound: bool,
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+    frame: usize,
+}
+
+impl Marquee {
+    /// Marquee of a text through a display of `width` digits, scrolling to the left.
+    ///
+    /// Returns `None` if the text has a character without a seven segment form.
+    pub fn new(text: &str, width: usize) -> Option<Self> {
+        let digits = text_segments(text).collect::<Option<Vec<_>>>()?;
+
+        Some(Marquee {
+            digits,
+            width,
+            direction: ScrollDirection::Left,
+            gap: 1,
+            wraparound: false,
+            size: DigitSize::NORMAL,
+            glyph_style: GlyphStyle::Heavy,
+            frame: 0,
+        })
+    }
+
+    /// Sets the scroll direction.
+    pub fn direction(mut self, direction: ScrollDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the blank digits between the end and the start of the text with wraparound, one by
+    /// default.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets if the text starts again after it has left the display, off by default.
+    pub fn wraparound(mut self, wraparound: bool) -> Self {
+        self.wraparound = wraparound;
+        self
+    }
+
+    /// Sets the size of the digits.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters the digits are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// Number of frames without wraparound.
+    fn frames(&self) -> usize {
+        self.digits.len() + self.width
+    }
+
+    /// Digit at a position of the display in a frame.
+    fn digit(&self, frame: usize, position: usize) -> Segments {
+        if self.wraparound {
+            let period = self.digits.len() + self.gap;
+            if period == 0 {
+                return Segments::NONE;
+            }
+
+            let idx = match self.direction {
+                ScrollDirection::Left => (frame + position) % period,
+                ScrollDirection::Right => {
+                    let shift = (frame + self.width) % period;
+                    (position + self.digits.len() + period - shift) % period
+                }
+            };
+            self.digits.get(idx).copied().unwrap_or(Segments::NONE)
+        } else {
+            let idx = match self.direction {
+                ScrollDirection::Left => (frame + position).checked_sub(self.width),
+                ScrollDirection::Right => (position + self.digits.len()).checked_sub(frame),
+            };
+            idx.and_then(|idx| self.digits.get(idx))
+                .copied()
+                .unwrap_or(Segments::NONE)
+        }
+    }
+}
+
+impl Iterator for Marquee {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if !self.wraparound && self.frame >= self.frames() {
+            return None;
+        }
+
+        let frame = self.frame;
+        self.frame += 1;
+
+        let mut text = String::new();
+        write_cells(
+            &mut text,
+            (0..self.width).map(|position| (false, self.digit(frame, position))),
+            self.size,
+            self.glyph_style,
+        )
+        .ok()?;
+
+        Some(text)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.wraparound {
+            (usize::MAX, None)
+        } else {
+            let remaining = self.frames().saturating_sub(self.frame);
+            (remaining, Some(remaining))
+        }
+    }
+}
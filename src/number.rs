@@ -0,0 +1,337 @@
+//! Styles of the displays of numbers.
+use crate::render::{write_cell_row, Cell, DigitSize, GlyphStyle};
+use crate::segments::dp_segments;
+use crate::{four_dp_digits, Segments};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// How a NaN or an infinite float is shown, see [`NumberStyle::non_finite`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, sevseg_four_dp_with, NonFinite, NumberStyle};
+/// let display = sevseg_four_dp_with(f64::NAN, NumberStyle::new().non_finite(NonFinite::Dashes));
+///
+/// assert_eq!(display, sevseg_four("----").unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NonFinite {
+    /// `nAn`, `InF` and `-InF`.
+    #[default]
+    Letters,
+    /// Four dashes.
+    Dashes,
+    /// `Err`.
+    Error,
+}
+
+impl NonFinite {
+    /// Four cells of a float formatted with `Display` if it is `NaN`, `inf` or `-inf`.
+    pub(crate) fn cells(self, number: &str) -> Option<[Cell; 4]> {
+        let negative = match number {
+            "NaN" => None,
+            "inf" => Some(false),
+            "-inf" => Some(true),
+            _ => return None,
+        };
+        let text = match (self, negative) {
+            (NonFinite::Letters, None) => " nAn",
+            (NonFinite::Letters, Some(false)) => " InF",
+            (NonFinite::Letters, Some(true)) => "-InF",
+            (NonFinite::Dashes, _) => "----",
+            (NonFinite::Error, _) => " Err",
+        };
+
+        let mut cells = [(false, Segments::NONE); 4];
+        for (cell, chr) in cells.iter_mut().zip(text.chars()) {
+            cell.1 = Segments::from_unit_char(chr)?;
+        }
+
+        Some(cells)
+    }
+}
+
+/// Where the minus sign of a negative number is drawn.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four_dp_with, NumberStyle, SignPolicy};
+/// let style = NumberStyle::new().sign(SignPolicy::Digit);
+///
+/// assert_eq!(sevseg_four_dp_with(-12, style), sevseg_four_dp_with(-12.0, style));
+/// assert_eq!(
+///     sevseg_four_dp_with(-12, style).lines().last(),
+///     Some("          ╹ ┗━━━╸⦁ ┗━━━┛"),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignPolicy {
+    /// In a narrow column merged in front of the leading digit of a negative number.
+    #[default]
+    Merged,
+    /// On a digit of its own, taking one of the digits of a negative number. A negative number
+    /// that does not fit into the digits left is shown as `Err` by
+    /// [`sevseg_four_dp_with`](crate::sevseg_four_dp_with).
+    Digit,
+    /// In a narrow column in front of the leading digit that is blank for other numbers, so
+    /// negative and positive numbers are equally wide.
+    Column,
+}
+
+impl SignPolicy {
+    /// Columns of the blank sign column in front of the cells of a number.
+    pub(crate) fn blank_columns(self, cells: &[Cell], size: DigitSize) -> usize {
+        match cells.first() {
+            Some(&(false, _)) if self == SignPolicy::Column => size.sign_columns(),
+            _ => 0,
+        }
+    }
+}
+
+/// How the digits of the integer part of a number are grouped by thousands.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_number_with, sevseg_render, Grouping, NumberStyle};
+/// let mut buf = [0; 512];
+/// let style = NumberStyle::new().grouping(Grouping::Point);
+///
+/// assert_eq!(
+///     sevseg_number_with(&1234567, 7, style).unwrap(),
+///     sevseg_render("1.234.567", &mut buf).unwrap()
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// No group separators.
+    #[default]
+    None,
+    /// A decimal point after every group, like `1.234,5`.
+    Point,
+    /// An apostrophe in a narrow column after every group, like `1'234.5`.
+    Apostrophe,
+    /// A blank narrow column after every group, like `1 234.5`.
+    Gap,
+}
+
+/// Style of the numbers of [`sevseg_four_dp_with`](crate::sevseg_four_dp_with) and
+/// [`sevseg_number_with`](crate::sevseg_number_with).
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four_dp, sevseg_four_dp_with, NumberStyle};
+/// assert_eq!(sevseg_four_dp_with(-1.5, NumberStyle::new()), sevseg_four_dp(-1.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberStyle {
+    pub(crate) sign: SignPolicy,
+    pub(crate) non_finite: NonFinite,
+    pub(crate) point: char,
+    pub(crate) grouping: Grouping,
+}
+
+impl NumberStyle {
+    /// Merged minus sign, NaN and infinity in letters, a point as decimal separator and no
+    /// grouping, the style of [`sevseg_four_dp`](crate::sevseg_four_dp).
+    pub fn new() -> Self {
+        NumberStyle {
+            sign: SignPolicy::Merged,
+            non_finite: NonFinite::Letters,
+            point: '.',
+            grouping: Grouping::None,
+        }
+    }
+
+    /// Sets where the minus sign is drawn.
+    pub fn sign(mut self, sign: SignPolicy) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Sets how a NaN or an infinite float is shown.
+    pub fn non_finite(mut self, non_finite: NonFinite) -> Self {
+        self.non_finite = non_finite;
+        self
+    }
+
+    /// Sets the decimal separator of numbers formatted with `Display`, such as a comma for
+    /// `"1.234,5"`. Other characters than digits are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seven_seg::{sevseg_four_dp, sevseg_four_dp_with, NumberStyle};
+    /// let style = NumberStyle::new().point(',');
+    ///
+    /// assert_eq!(sevseg_four_dp_with("1.234,5", style), sevseg_four_dp(1234.5));
+    /// ```
+    pub fn point(mut self, point: char) -> Self {
+        self.point = point;
+        self
+    }
+
+    /// Sets how the digits of the integer part are grouped by thousands.
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Four cells of a number formatted with `Display`.
+    pub(crate) fn four_cells(self, number: &str) -> [Cell; 4] {
+        if let Some(cells) = self.non_finite.cells(number) {
+            return cells;
+        }
+
+        let mut cells = four_dp_digits(number, self.point).map(dp_segments);
+        if self.sign == SignPolicy::Digit {
+            if let Some(positive) = number.strip_prefix('-') {
+                // The sign digit leaves three digits, a larger integer part shows the error.
+                let integer = positive
+                    .chars()
+                    .take_while(|&chr| chr != self.point)
+                    .filter(char::is_ascii_digit)
+                    .count();
+                if integer > 3 {
+                    return NonFinite::Error
+                        .cells("NaN")
+                        .unwrap_or([(false, Segments::NONE); 4]);
+                }
+                cells = four_dp_digits(positive, self.point).map(dp_segments);
+                cells.rotate_right(1);
+                cells[0] = (false, Segments::G);
+            }
+        }
+
+        cells
+    }
+
+    /// Sets the decimal points of [`Grouping::Point`] and returns the indexes of the cells
+    /// followed by a separator column of the other groupings.
+    pub(crate) fn group_cells(self, cells: &mut [Cell]) -> Vec<usize> {
+        // Sign digits and blanks in front of the number are dashes or no segments.
+        let Some(start) = cells
+            .iter()
+            .position(|&(_, segments)| segments != Segments::G && !segments.is_empty())
+        else {
+            return Vec::new();
+        };
+        let end = cells[start..]
+            .iter()
+            .position(|&(_, segments)| segments.contains(Segments::DP))
+            .map_or(cells.len(), |idx| start + idx + 1);
+        let groups = (start..end - 1)
+            .filter(|idx| (end - 1 - idx) % 3 == 0)
+            .collect::<Vec<_>>();
+
+        match self.grouping {
+            Grouping::None => Vec::new(),
+            Grouping::Point => {
+                for &idx in &groups {
+                    cells[idx].1 |= Segments::DP;
+                }
+                Vec::new()
+            }
+            Grouping::Apostrophe | Grouping::Gap => groups,
+        }
+    }
+}
+
+impl Default for NumberStyle {
+    fn default() -> Self {
+        NumberStyle::new()
+    }
+}
+
+/// Writes the cells of a number side by side, separated by a space, behind the blank sign column
+/// of the sign policy and with the separator columns of the grouping after the group cells.
+pub(crate) fn write_number_cells<W: Write>(
+    out: &mut W,
+    cells: &[Cell],
+    groups: &[usize],
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+    style: NumberStyle,
+) -> fmt::Result {
+    let blank = style.sign.blank_columns(cells, size);
+    for row in 0..size.rows() {
+        for _ in 0..blank {
+            out.write_char(' ')?;
+        }
+        for (idx, &cell) in cells.iter().enumerate() {
+            if idx > 0 {
+                out.write_char(' ')?;
+            }
+            write_cell_row(out, cell, size, glyph_style, row)?;
+            if groups.contains(&idx) {
+                out.write_char(match style.grouping {
+                    Grouping::Apostrophe if row == 0 => glyph_style.apostrophe_char(),
+                    _ => ' ',
+                })?;
+            }
+        }
+        out.write_char('\n')?;
+    }
+
+    Ok(())
+}
+
+/// Digits of a number without the sign digit of the policy, `None` if none are left.
+pub(crate) fn number_digits(digits: usize, negative: bool, sign: SignPolicy) -> Option<usize> {
+    let sign_digits = if sign == SignPolicy::Digit && negative {
+        1
+    } else {
+        0
+    };
+
+    digits.checked_sub(sign_digits).filter(|&digits| digits > 0)
+}
+
+/// Cells of the digits and the decimal point of a number, with the minus sign of a negative number
+/// that is not zero merged into the first digit or on a digit of its own.
+pub(crate) fn number_cells(text: &str, negative: bool, sign: SignPolicy) -> Vec<Cell> {
+    let mut cells = Vec::<Cell>::new();
+    for chr in text.chars() {
+        match chr.to_digit(10) {
+            Some(digit) => cells.push(dp_segments(digit as usize)),
+            None => {
+                if let Some((_, segments)) = cells.last_mut() {
+                    *segments |= Segments::DP;
+                }
+            }
+        }
+    }
+
+    let minus = negative && text.chars().any(|chr| ('1'..='9').contains(&chr));
+    if sign == SignPolicy::Digit && negative {
+        let segments = if minus { Segments::G } else { Segments::NONE };
+        cells.insert(0, (false, segments));
+    } else if minus {
+        cells[0].0 = true;
+    }
+
+    cells
+}
+
+/// Display of the cells of a number in the normal size and heavy lines.
+pub(crate) fn render_number(cells: &[Cell], style: NumberStyle) -> Option<String> {
+    let mut cells = cells.to_vec();
+    let groups = style.group_cells(&mut cells);
+
+    let mut display = String::new();
+    write_number_cells(
+        &mut display,
+        &cells,
+        &groups,
+        DigitSize::NORMAL,
+        GlyphStyle::Heavy,
+        style,
+    )
+    .ok()?;
+
+    Some(display)
+}
@@ -0,0 +1,60 @@
+//! Texts with a decimal point on any digit, such as addresses, dates and versions.
+use crate::render::{write_cells, DigitSize, GlyphStyle};
+use crate::segments::str_segments;
+use crate::Segments;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Seven segment display of a text on a panel of a number of digits with a decimal point each.
+///
+/// Takes digits from zero to nine, dashes, spaces and any number of points, every point sets the
+/// decimal point of the digit before, or of a blank digit if there is no digit before it, like
+/// `"192.168.1.1"`, `"18.10.26"` or `"1.2.3"`. The text is right aligned on the panel. Returns
+/// `None` if the text has any other character or more digits than the panel.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_points, sevseg_render};
+/// let mut buf = [0; 512];
+///
+/// assert_eq!(
+///     sevseg_points("1.2.3", 3).unwrap(),
+///     sevseg_render("1.2.3", &mut buf).unwrap()
+/// );
+/// assert_eq!(
+///     sevseg_points("1.2.3", 4).unwrap(),
+///     sevseg_render(" 1.2.3", &mut buf).unwrap()
+/// );
+/// assert_eq!(sevseg_points("192.168.1.1", 6), None);
+/// ```
+///
+/// ```
+/// # use seven_seg::sevseg_points;
+/// let display = sevseg_points("1..2.", 3).unwrap();
+///
+/// assert_eq!(display.lines().collect::<Vec<_>>(), [
+///     "    ╻         ╺━━━┓ ",
+///     "    ┃             ┃ ",
+///     "    ┃         ┏━━━┛ ",
+///     "    ┃         ┃     ",
+///     "    ╹⦁      ⦁ ┗━━━╸⦁",
+/// ]);
+/// ```
+pub fn sevseg_points(text: &str, digits: usize) -> Option<String> {
+    let cells = str_segments(text).collect::<Option<Vec<_>>>()?;
+    let blanks = digits.checked_sub(cells.len())?;
+
+    let mut display = String::new();
+    write_cells(
+        &mut display,
+        core::iter::repeat_n(Segments::NONE, blanks)
+            .chain(cells)
+            .map(|segments| (false, segments)),
+        DigitSize::NORMAL,
+        GlyphStyle::Heavy,
+    )
+    .ok()?;
+
+    Some(display)
+}
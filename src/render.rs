@@ -0,0 +1,408 @@
+//! Text rendering of segments without allocation.
+use crate::segments::str_segments;
+use crate::Segments;
+use core::fmt::{self, Write};
+
+/// Size of a rendered digit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::DigitSize;
+/// assert_eq!(DigitSize::NORMAL.columns(), 5);
+/// assert_eq!(DigitSize::NORMAL.rows(), 5);
+/// assert_eq!(DigitSize::new(7, 3).rows(), 9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitSize {
+    horizontal: usize,
+    vertical: usize,
+}
+
+impl DigitSize {
+    /// Digits three columns wide and five lines high.
+    pub const SMALL: DigitSize = DigitSize::new(1, 1);
+    /// Digits five columns wide and five lines high, the size of [`sevseg_four`](crate::sevseg_four).
+    pub const NORMAL: DigitSize = DigitSize::new(3, 1);
+    /// Digits seven columns wide and seven lines high.
+    pub const LARGE: DigitSize = DigitSize::new(5, 2);
+    /// Digits nine columns wide and nine lines high.
+    pub const HUGE: DigitSize = DigitSize::new(7, 3);
+    /// The predefined sizes from the smallest to the largest.
+    pub const ALL: [DigitSize; 4] = [
+        DigitSize::SMALL,
+        DigitSize::NORMAL,
+        DigitSize::LARGE,
+        DigitSize::HUGE,
+    ];
+
+    /// Size from the length of the horizontal and of the vertical segments, at least one.
+    pub const fn new(horizontal: usize, vertical: usize) -> Self {
+        DigitSize {
+            horizontal: if horizontal == 0 { 1 } else { horizontal },
+            vertical: if vertical == 0 { 1 } else { vertical },
+        }
+    }
+
+    /// Columns of a digit without decimal point.
+    pub const fn columns(self) -> usize {
+        self.horizontal + 2
+    }
+
+    /// Lines of a digit.
+    pub const fn rows(self) -> usize {
+        self.vertical * 2 + 3
+    }
+
+    /// Bytes of a buffer large enough for [`sevseg_render_with`] to render a string of `chars`
+    /// characters in this size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seven_seg::{sevseg_render_with, DigitSize, GlyphStyle};
+    /// let size = DigitSize::LARGE;
+    /// let mut buf = vec![0; size.buffer_len("12.5".len())];
+    ///
+    /// assert!(sevseg_render_with("12.5", size, GlyphStyle::Heavy, &mut buf).is_some());
+    /// ```
+    pub const fn buffer_len(self, chars: usize) -> usize {
+        // Every character a digit with a minus sign, a point and a separator, four bytes a column.
+        let columns = chars * (self.sign_columns() + self.columns() + 2);
+        self.rows() * (columns * 4 + 1)
+    }
+
+    /// Columns of a minus sign merged in front of a digit.
+    pub(crate) const fn sign_columns(self) -> usize {
+        self.horizontal.div_ceil(2)
+    }
+
+    const fn middle(self) -> usize {
+        self.vertical + 1
+    }
+}
+
+impl Default for DigitSize {
+    fn default() -> Self {
+        DigitSize::NORMAL
+    }
+}
+
+/// Characters the lines of rendered digits are drawn with.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_render_with, DigitSize, GlyphStyle};
+/// let mut buf = [0; 256];
+/// let text = sevseg_render_with("4.2", DigitSize::NORMAL, GlyphStyle::Ascii, &mut buf).unwrap();
+///
+/// assert_eq!(text, "|   |  ----+\n\
+///                   |   |      |\n\
+///                   +---+  +---+\n\
+///                   \x20   |  |    \n\
+///                   \x20   |. +----\n"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlyphStyle {
+    /// Heavy box drawing lines, the style of [`sevseg_four`](crate::sevseg_four).
+    #[default]
+    Heavy,
+    /// Light box drawing lines.
+    Light,
+    /// Dashes, bars and plus signs of plain ASCII.
+    Ascii,
+}
+
+impl GlyphStyle {
+    /// Character of the lines leaving the middle of a cell.
+    fn line_char(self, lines: usize) -> char {
+        match self {
+            GlyphStyle::Heavy => HEAVY[lines],
+            GlyphStyle::Light => LIGHT[lines],
+            GlyphStyle::Ascii => match lines {
+                0 => ' ',
+                UP | DOWN | VERTICAL => '|',
+                LEFT | RIGHT | HORIZONTAL => '-',
+                _ => '+',
+            },
+        }
+    }
+
+    /// Character of a lit decimal point.
+    pub const fn point_char(self) -> char {
+        match self {
+            GlyphStyle::Heavy => '⦁',
+            GlyphStyle::Light => '•',
+            GlyphStyle::Ascii => '.',
+        }
+    }
+
+    /// Character of an apostrophe in the top line.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn apostrophe_char(self) -> char {
+        match self {
+            GlyphStyle::Heavy => '╻',
+            GlyphStyle::Light => '╷',
+            GlyphStyle::Ascii => '\'',
+        }
+    }
+}
+
+/// A digit with a minus sign merged in front of it if the flag is set.
+pub(crate) type Cell = (bool, Segments);
+
+/// Box drawing characters indexed by the lines leaving the middle of a cell,
+/// bit `0` up, bit `1` down, bit `2` left, bit `3` right.
+#[rustfmt::skip]
+const HEAVY: [char; 16] = [
+    ' ', '╹', '╻', '┃', '╸', '┛', '┓', '┫',
+    '╺', '┗', '┏', '┣', '━', '┻', '┳', '╋',
+];
+
+/// Light box drawing characters in the order of [`HEAVY`].
+#[rustfmt::skip]
+const LIGHT: [char; 16] = [
+    ' ', '╵', '╷', '│', '╴', '┘', '┐', '┤',
+    '╶', '└', '┌', '├', '─', '┴', '┬', '┼',
+];
+
+const UP: usize = 1;
+const DOWN: usize = 1 << 1;
+const LEFT: usize = 1 << 2;
+const RIGHT: usize = 1 << 3;
+const VERTICAL: usize = UP | DOWN;
+const HORIZONTAL: usize = LEFT | RIGHT;
+
+/// Character of the glyph of a digit at a line and column.
+fn glyph_char(
+    segments: Segments,
+    size: DigitSize,
+    style: GlyphStyle,
+    row: usize,
+    col: usize,
+) -> char {
+    let lit = |segment, line| {
+        if segments.contains(segment) {
+            line
+        } else {
+            0
+        }
+    };
+    let bottom = size.rows() - 1;
+    let right = size.columns() - 1;
+    let horizontal = match row {
+        0 => Segments::A,
+        row if row == size.middle() => Segments::G,
+        row if row == bottom => Segments::D,
+        _ => Segments::NONE,
+    };
+    let (upper, lower) = if col == 0 {
+        (Segments::F, Segments::E)
+    } else {
+        (Segments::B, Segments::C)
+    };
+
+    let lines = if col != 0 && col != right {
+        if horizontal.is_empty() {
+            0
+        } else {
+            lit(horizontal, LEFT | RIGHT)
+        }
+    } else if horizontal.is_empty() {
+        let vertical = if row < size.middle() { upper } else { lower };
+        lit(vertical, UP | DOWN)
+    } else {
+        let up = match row {
+            0 => 0,
+            row if row == size.middle() => lit(upper, UP),
+            _ => lit(lower, UP),
+        };
+        let down = match row {
+            0 => lit(upper, DOWN),
+            row if row == size.middle() => lit(lower, DOWN),
+            _ => 0,
+        };
+        let side = if col == 0 { RIGHT } else { LEFT };
+
+        up | down | lit(horizontal, side)
+    };
+
+    style.line_char(lines)
+}
+
+/// Columns of a digit with its merged minus sign and decimal point.
+#[cfg(feature = "ratatui")]
+pub(crate) fn cell_columns((minus, segments): Cell, size: DigitSize) -> usize {
+    size.columns()
+        + if minus { size.sign_columns() } else { 0 }
+        + if segments.contains(Segments::DP) {
+            1
+        } else {
+            0
+        }
+}
+
+/// Columns of digits side by side, separated by a space.
+#[cfg(feature = "ratatui")]
+pub(crate) fn cells_columns<I: Iterator<Item = Cell>>(cells: I, size: DigitSize) -> usize {
+    cells
+        .map(|cell| cell_columns(cell, size) + 1)
+        .sum::<usize>()
+        .saturating_sub(1)
+}
+
+/// Writes one line of a digit, the decimal point column only if it is lit.
+///
+/// A merged minus sign is drawn in front of the digit, or in the empty space of a digit without
+/// left and middle segments.
+pub(crate) fn write_cell_row<W: Write>(
+    out: &mut W,
+    (minus, segments): Cell,
+    size: DigitSize,
+    style: GlyphStyle,
+    row: usize,
+) -> fmt::Result {
+    let sign_row = minus && row == size.middle();
+    let hug = !segments.contains(Segments::E)
+        && !segments.contains(Segments::F)
+        && !segments.contains(Segments::G);
+    let sign_cols = size.sign_columns();
+    let sign = style.line_char(HORIZONTAL);
+
+    if minus {
+        for _ in 0..sign_cols {
+            out.write_char(if sign_row && !hug { sign } else { ' ' })?;
+        }
+    }
+
+    for col in 0..size.columns() {
+        let chr = glyph_char(segments, size, style, row, col);
+        let in_sign = col + 1 + sign_cols >= size.columns() && col + 1 < size.columns();
+        out.write_char(if sign_row && hug && in_sign {
+            sign
+        } else {
+            chr
+        })?;
+    }
+
+    if segments.contains(Segments::DP) {
+        out.write_char(if row == size.rows() - 1 {
+            style.point_char()
+        } else {
+            ' '
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Writes the digits side by side, separated by a space.
+pub(crate) fn write_cells<W, I>(
+    out: &mut W,
+    cells: I,
+    size: DigitSize,
+    style: GlyphStyle,
+) -> fmt::Result
+where
+    W: Write,
+    I: Iterator<Item = Cell> + Clone,
+{
+    for row in 0..size.rows() {
+        for (idx, cell) in cells.clone().enumerate() {
+            if idx > 0 {
+                out.write_char(' ')?;
+            }
+            write_cell_row(out, cell, size, style, row)?;
+        }
+        out.write_char('\n')?;
+    }
+
+    Ok(())
+}
+
+/// Seven segment display of a string rendered into a buffer.
+///
+/// Any number of digits from zero to nine, dashes and spaces, a point sets the decimal point of
+/// the digit before. Returns the rendered text at the start of the buffer, or `None` if the string
+/// has any other character or the buffer is too small.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::sevseg_render;
+/// let mut buf = [0; 256];
+/// let text = sevseg_render("80.2", &mut buf).unwrap();
+///
+/// assert_eq!(text, "┏━━━┓ ┏━━━┓  ╺━━━┓\n\
+///                   ┃   ┃ ┃   ┃      ┃\n\
+///                   ┣━━━┫ ┃   ┃  ┏━━━┛\n\
+///                   ┃   ┃ ┃   ┃  ┃    \n\
+///                   ┗━━━┛ ┗━━━┛⦁ ┗━━━╸\n"
+/// );
+/// ```
+pub fn sevseg_render<'a>(string: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    sevseg_render_with(string, DigitSize::NORMAL, GlyphStyle::Heavy, buf)
+}
+
+/// Seven segment display of a string in a size and glyph style rendered into a buffer.
+///
+/// The characters and the result are those of [`sevseg_render`].
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_render_with, DigitSize, GlyphStyle};
+/// let mut buf = [0; 256];
+/// let text = sevseg_render_with("-1", DigitSize::SMALL, GlyphStyle::Light, &mut buf).unwrap();
+///
+/// assert_eq!(text.lines().collect::<Vec<_>>(), [
+///     "      ╷",
+///     "      │",
+///     "╶─╴   │",
+///     "      │",
+///     "      ╵",
+/// ]);
+/// ```
+pub fn sevseg_render_with<'a>(
+    string: &str,
+    size: DigitSize,
+    style: GlyphStyle,
+    buf: &'a mut [u8],
+) -> Option<&'a str> {
+    let digits = str_segments(string);
+    if digits.clone().any(|segments| segments.is_none()) {
+        return None;
+    }
+
+    let mut out = SliceWriter { buf, len: 0 };
+    write_cells(
+        &mut out,
+        digits.flatten().map(|segments| (false, segments)),
+        size,
+        style,
+    )
+    .ok()?;
+    let SliceWriter { buf, len } = out;
+
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+//! Values scaled into a number of digits with an SI prefix or an exponent.
+use crate::number::{number_cells, number_digits, render_number};
+use crate::{DigitSize, NumberStyle, Segments, SignPolicy};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use num::ToPrimitive;
+
+/// SI prefixes from none to tera with their divisors.
+const PREFIXES: [(char, f64); 5] = [(' ', 1.0), ('k', 1e3), ('M', 1e6), ('G', 1e9), ('T', 1e12)];
+
+/// How [`sevseg_si`] shows the SI prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixStyle {
+    /// A letter digit after the digits, blank without prefix.
+    Digit,
+    /// A text letter beside the bottom line of the digits, blank without prefix.
+    Label,
+}
+
+/// Seven segment display of a value scaled into a number of digits with an SI prefix.
+///
+/// The value is divided by the one of one, kilo, mega, giga and tera that leaves one to three
+/// integer digits, or by a larger one if they do not fit into the digits. The rest of the digits
+/// are decimal places. The decimal point and the merged minus sign are those of
+/// [`sevseg_four_dp`](crate::sevseg_four_dp). Returns `None` for zero digits, values that are not
+/// finite and values of a thousand tera and more that do not fit.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_si, PrefixStyle};
+/// let display = sevseg_si(2_400_000_000_u64, 4, PrefixStyle::Digit).unwrap();
+///
+/// assert_eq!(display, "╺━━━┓  ╻   ╻ ┏━━━┓ ┏━━━┓ ┏━━━╸\n\
+///                      \x20   ┃  ┃   ┃ ┃   ┃ ┃   ┃ ┃    \n\
+///                      ┏━━━┛  ┗━━━┫ ┃   ┃ ┃   ┃ ┃   ╻\n\
+///                      ┃          ┃ ┃   ┃ ┃   ┃ ┃   ┃\n\
+///                      ┗━━━╸⦁     ╹ ┗━━━┛ ┗━━━┛ ┗━━━┛\n"
+/// );
+/// ```
+///
+/// ```
+/// # use seven_seg::{sevseg_si, PrefixStyle};
+/// let display = sevseg_si(-1536.0, 3, PrefixStyle::Label).unwrap();
+///
+/// assert_eq!(display, "      ╻  ┏━━━╸ ╻   ╻  \n\
+///                      \x20     ┃  ┃     ┃   ┃  \n\
+///                      \x20   ━━┃  ┗━━━┓ ┗━━━┫  \n\
+///                      \x20     ┃      ┃     ┃  \n\
+///                      \x20     ╹⦁ ╺━━━┛     ╹ k\n"
+/// );
+/// ```
+#[inline]
+pub fn sevseg_si<T: ToPrimitive>(n: T, digits: usize, style: PrefixStyle) -> Option<String> {
+    sevseg_si_with(n, digits, style, SignPolicy::Merged)
+}
+
+/// Seven segment display of a value scaled with an SI prefix like [`sevseg_si`], with the minus
+/// sign drawn by the sign policy.
+///
+/// The sign digit of [`SignPolicy::Digit`] is one of the digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_si_with, PrefixStyle, SignPolicy};
+/// let display = sevseg_si_with(-1536, 3, PrefixStyle::Label, SignPolicy::Digit).unwrap();
+///
+/// assert_eq!(display.lines().collect::<Vec<_>>(), [
+///     "          ╻  ┏━━━╸  ",
+///     "          ┃  ┃      ",
+///     "╺━━━╸     ┃  ┗━━━┓  ",
+///     "          ┃      ┃  ",
+///     "          ╹⦁ ╺━━━┛ k",
+/// ]);
+/// ```
+pub fn sevseg_si_with<T: ToPrimitive>(
+    n: T,
+    digits: usize,
+    style: PrefixStyle,
+    sign: SignPolicy,
+) -> Option<String> {
+    let value = n.to_f64()?;
+    if !value.is_finite() {
+        return None;
+    }
+    let digits = number_digits(digits, value < 0.0, sign)?;
+
+    let (text, prefix) = PREFIXES
+        .iter()
+        .enumerate()
+        .find_map(|(idx, &(prefix, divisor))| {
+            let scaled = value.abs() / divisor;
+            let integer_digits = format!("{}", scaled as u64).len();
+            if integer_digits > 3 && idx + 1 < PREFIXES.len() {
+                return None;
+            }
+            let decimals = digits.checked_sub(integer_digits)?;
+
+            // Rounding can carry into one more integer digit.
+            (0..=decimals)
+                .rev()
+                .take(2)
+                .map(|decimals| format!("{:.*}", decimals, scaled))
+                .find(|text| text.chars().filter(char::is_ascii_digit).count() <= digits)
+                .map(|text| (text, prefix))
+        })?;
+
+    let mut cells = number_cells(&text, value < 0.0, sign);
+    if style == PrefixStyle::Digit {
+        cells.push((false, Segments::from_unit_char(prefix)?));
+    }
+
+    let mut display = render_number(&cells, NumberStyle::new().sign(sign))?;
+    if style == PrefixStyle::Label {
+        let bottom = DigitSize::NORMAL.rows() - 1;
+        display = display
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let label = if row == bottom { prefix } else { ' ' };
+                format!("{} {}\n", line, label)
+            })
+            .collect();
+    }
+
+    Some(display)
+}
+
+/// Seven segment display of a value in scientific notation within a number of digits.
+///
+/// The mantissa is followed by an `E` and the exponent, the `E` is left out when it would leave a
+/// single mantissa digit before a negative exponent. A negative exponent takes a dash digit, the
+/// decimal point and the merged minus sign of the mantissa are those of
+/// [`sevseg_four_dp`](crate::sevseg_four_dp). Returns `None` for values that are not finite and
+/// digits too few for a mantissa digit and the exponent.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::sevseg_sci;
+/// let display = sevseg_sci(0.000012, 6).unwrap();
+///
+/// assert_eq!(display.lines().collect::<Vec<_>>(), [
+///     "    ╻  ╺━━━┓ ┏━━━┓ ┏━━━╸       ┏━━━╸",
+///     "    ┃      ┃ ┃   ┃ ┃           ┃    ",
+///     "    ┃  ┏━━━┛ ┃   ┃ ┣━━━╸ ╺━━━╸ ┗━━━┓",
+///     "    ┃  ┃     ┃   ┃ ┃               ┃",
+///     "    ╹⦁ ┗━━━╸ ┗━━━┛ ┗━━━╸       ╺━━━┛",
+/// ]);
+/// ```
+///
+/// ```
+/// # use seven_seg::sevseg_sci;
+/// let display = sevseg_sci(0.000012, 4).unwrap();
+///
+/// assert_eq!(display.lines().collect::<Vec<_>>(), [
+///     "    ╻  ╺━━━┓       ┏━━━╸",
+///     "    ┃      ┃       ┃    ",
+///     "    ┃  ┏━━━┛ ╺━━━╸ ┗━━━┓",
+///     "    ┃  ┃               ┃",
+///     "    ╹⦁ ┗━━━╸       ╺━━━┛",
+/// ]);
+/// ```
+#[inline]
+pub fn sevseg_sci<T: ToPrimitive>(n: T, digits: usize) -> Option<String> {
+    sevseg_sci_with(n, digits, SignPolicy::Merged)
+}
+
+/// Seven segment display of a value in scientific notation like [`sevseg_sci`], with the minus
+/// sign of the mantissa drawn by the sign policy.
+///
+/// The sign digit of [`SignPolicy::Digit`] is one of the digits.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_sci_with, SignPolicy};
+/// let display = sevseg_sci_with(-0.000012, 5, SignPolicy::Digit).unwrap();
+///
+/// assert_eq!(display.lines().nth(2), Some("╺━━━╸     ┃  ┏━━━┛ ╺━━━╸ ┗━━━┓"));
+/// ```
+pub fn sevseg_sci_with<T: ToPrimitive>(n: T, digits: usize, sign: SignPolicy) -> Option<String> {
+    let value = n.to_f64()?;
+    if !value.is_finite() {
+        return None;
+    }
+    let digits = number_digits(digits, value < 0.0, sign)?;
+
+    // Rounding the mantissa can carry into the next exponent, the digits are laid out again with
+    // it. The value rounded to the digits a shorter exponent leaves does not carry, but it rounds
+    // to a mantissa of one and zeros in the carried exponent just the same.
+    let (mantissa_digits, _) = sci_layout(exponent_of(&format!("{:e}", value)), digits)?;
+    let exponent = exponent_of(&format!("{:.*e}", mantissa_digits - 1, value.abs()));
+    let (mantissa_digits, with_e) = sci_layout(exponent, digits)?;
+    let text = format!("{:.*e}", mantissa_digits - 1, value.abs());
+    let mantissa = match text.split_once('e') {
+        Some((mantissa, _)) if exponent_of(&text) == exponent => mantissa.to_string(),
+        _ => format!("{:.*}", mantissa_digits - 1, 1.0),
+    };
+
+    let mut cells = number_cells(&mantissa, value < 0.0, sign);
+    if with_e {
+        cells.push((false, Segments::from_unit_char('E')?));
+    }
+    cells.extend(
+        exponent
+            .to_string()
+            .chars()
+            .map(|chr| Some((false, Segments::from_char(chr)?)))
+            .collect::<Option<Vec<_>>>()?,
+    );
+
+    render_number(&cells, NumberStyle::new().sign(sign))
+}
+
+/// Mantissa digits the exponent leaves in the digits, and whether an `E` fits between them.
+fn sci_layout(exponent: i32, digits: usize) -> Option<(usize, bool)> {
+    let exponent_digits =
+        exponent.unsigned_abs().to_string().len() + if exponent < 0 { 1 } else { 0 };
+    let (mantissa_digits, with_e) = match digits.checked_sub(exponent_digits + 1) {
+        Some(mantissa_digits) if mantissa_digits >= 2 || exponent >= 0 => (mantissa_digits, true),
+        _ => (digits.checked_sub(exponent_digits)?, false),
+    };
+
+    (mantissa_digits > 0).then_some((mantissa_digits, with_e))
+}
+
+/// Exponent of a number formatted with `{:e}`.
+fn exponent_of(text: &str) -> i32 {
+    text.split_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0)
+}
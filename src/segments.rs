@@ -0,0 +1,241 @@
+//! Segments of one digit.
+//!
+//! ```text
+//!  ━a━
+//! f   b
+//!  ━g━
+//! e   c
+//!  ━d━  dp
+//! ```
+use core::ops::{BitOr, BitOrAssign};
+
+/// Set of lit segments of one digit.
+///
+/// Bit `0` is segment `a`, bit `6` is segment `g`, bit `7` is the decimal point.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::Segments;
+/// let one = Segments::from_char('1').unwrap();
+///
+/// assert_eq!(one, Segments::B | Segments::C);
+/// assert_eq!(one.bits(), 0b0000_0110);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segments(u8);
+
+impl Segments {
+    /// No segment lit.
+    pub const NONE: Segments = Segments(0);
+    /// Top segment.
+    pub const A: Segments = Segments(1);
+    /// Upper right segment.
+    pub const B: Segments = Segments(1 << 1);
+    /// Lower right segment.
+    pub const C: Segments = Segments(1 << 2);
+    /// Bottom segment.
+    pub const D: Segments = Segments(1 << 3);
+    /// Lower left segment.
+    pub const E: Segments = Segments(1 << 4);
+    /// Upper left segment.
+    pub const F: Segments = Segments(1 << 5);
+    /// Middle segment.
+    pub const G: Segments = Segments(1 << 6);
+    /// Decimal point.
+    pub const DP: Segments = Segments(1 << 7);
+
+    /// The seven segments `a` to `g` in order, without the decimal point.
+    pub const ALL: [Segments; 7] = [
+        Segments::A,
+        Segments::B,
+        Segments::C,
+        Segments::D,
+        Segments::E,
+        Segments::F,
+        Segments::G,
+    ];
+
+    /// Segments from the raw bits.
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        Segments(bits)
+    }
+
+    /// Raw bits of the segments.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Segments of a digit from zero to nine, a dash or a blank space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seven_seg::Segments;
+    /// assert_eq!(Segments::from_char('-'), Some(Segments::G));
+    /// assert_eq!(Segments::from_char(' '), Some(Segments::NONE));
+    /// assert_eq!(Segments::from_char('x'), None);
+    /// ```
+    pub fn from_char(chr: char) -> Option<Self> {
+        match chr {
+            '0'..='9' => Some(SEGMENTS[chr.to_digit(10)? as usize]),
+            '-' => Some(SEGMENTS[10]),
+            ' ' => Some(Segments::NONE),
+            _ => None,
+        }
+    }
+
+    /// Segments of a character of a unit, the characters of [`from_char`](Segments::from_char),
+    /// the degree sign and the letters with a seven segment form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seven_seg::Segments;
+    /// assert_eq!(
+    ///     Segments::from_unit_char('°'),
+    ///     Some(Segments::A | Segments::B | Segments::F | Segments::G)
+    /// );
+    /// assert_eq!(Segments::from_unit_char('c'), Some(Segments::D | Segments::E | Segments::G));
+    /// assert_eq!(Segments::from_unit_char('W'), None);
+    /// ```
+    pub fn from_unit_char(chr: char) -> Option<Self> {
+        let bits = match chr {
+            '°' => 0b0110_0011,
+            'A' => 0b0111_0111,
+            'b' => 0b0111_1100,
+            'C' => 0b0011_1001,
+            'c' => 0b0101_1000,
+            'd' => 0b0101_1110,
+            'E' => 0b0111_1001,
+            'F' => 0b0111_0001,
+            'G' => 0b0011_1101,
+            'H' => 0b0111_0110,
+            'h' => 0b0111_0100,
+            'I' => 0b0011_0000,
+            'J' => 0b0001_1110,
+            'k' => 0b0111_0101,
+            'L' => 0b0011_1000,
+            'M' => 0b0011_0111,
+            'n' => 0b0101_0100,
+            'o' => 0b0101_1100,
+            'P' => 0b0111_0011,
+            'r' => 0b0101_0000,
+            't' | 'T' => 0b0111_1000,
+            'U' => 0b0011_1110,
+            'u' => 0b0001_1100,
+            'y' => 0b0110_1110,
+            chr => return Segments::from_char(chr),
+        };
+
+        Some(Segments(bits))
+    }
+
+    /// Returns `true` if all segments of `other` are lit.
+    #[inline]
+    pub const fn contains(self, other: Segments) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no segment is lit.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Lower case name of a single segment, `"a"` to `"g"` or `"dp"`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Segments::A => "a",
+            Segments::B => "b",
+            Segments::C => "c",
+            Segments::D => "d",
+            Segments::E => "e",
+            Segments::F => "f",
+            Segments::G => "g",
+            Segments::DP => "dp",
+            _ => "",
+        }
+    }
+}
+
+impl BitOr for Segments {
+    type Output = Segments;
+
+    #[inline]
+    fn bitor(self, rhs: Segments) -> Segments {
+        Segments(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Segments {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Segments) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Segments in the order of the `SEVEN_SEG` glyphs, digits zero to nine and a dash.
+#[rustfmt::skip]
+pub(crate) const SEGMENTS: [Segments; 11] = [
+    Segments(0b0011_1111),
+    Segments(0b0000_0110),
+    Segments(0b0101_1011),
+    Segments(0b0100_1111),
+    Segments(0b0110_0110),
+    Segments(0b0110_1101),
+    Segments(0b0111_1101),
+    Segments(0b0000_0111),
+    Segments(0b0111_1111),
+    Segments(0b0110_1111),
+    Segments(0b0100_0000),
+];
+
+/// Segments and minus sign of a `SEVEN_SEG_DP` glyph index.
+#[cfg(feature = "alloc")]
+pub(crate) fn dp_segments(idx: usize) -> (bool, Segments) {
+    let mut segments = SEGMENTS[idx % 10];
+    if idx % 20 >= 10 {
+        segments |= Segments::DP;
+    }
+
+    (idx >= 20, segments)
+}
+
+/// Segments of the characters of a string, `None` for a character without segments.
+///
+/// A point sets the decimal point of the digit before or is a digit of its own.
+pub(crate) fn str_segments(string: &str) -> impl Iterator<Item = Option<Segments>> + Clone + '_ {
+    chars_segments(string, Segments::from_char)
+}
+
+/// Segments of the characters of a text with letters, see [`Segments::from_unit_char`].
+#[cfg(feature = "alloc")]
+pub(crate) fn text_segments(string: &str) -> impl Iterator<Item = Option<Segments>> + Clone + '_ {
+    chars_segments(string, Segments::from_unit_char)
+}
+
+fn chars_segments(
+    string: &str,
+    from_char: fn(char) -> Option<Segments>,
+) -> impl Iterator<Item = Option<Segments>> + Clone + '_ {
+    let mut chars = string.chars().peekable();
+
+    core::iter::from_fn(move || {
+        let segments = match chars.next()? {
+            '.' => return Some(Some(Segments::DP)),
+            chr => from_char(chr),
+        };
+
+        Some(segments.map(|segments| {
+            if chars.next_if_eq(&'.').is_some() {
+                segments | Segments::DP
+            } else {
+                segments
+            }
+        }))
+    })
+}
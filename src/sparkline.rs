@@ -0,0 +1,145 @@
+//! History of recent values drawn beneath a display.
+use crate::layout::visible_width;
+use std::collections::VecDeque;
+
+/// Bars of the sparkline from the lowest to the highest value.
+const LEVELS: [char; 5] = ['▁', '▂', '▃', '▅', '▇'];
+
+/// Ring buffer of recent values drawn as a sparkline beneath a display.
+///
+/// The sparkline is as wide as the display, one column per value with the newest on the right,
+/// scaled from the lowest to the highest value it shows. A line of markers beneath points out the
+/// newest highest value with `▴` and the newest lowest value with `▾`, unless all values are equal.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, Sparkline};
+/// let mut history = Sparkline::new(60);
+/// for value in [1200, 1800, 3400, 2600, 800, 1600] {
+///     history.push(f64::from(value));
+/// }
+///
+/// let display = history.render(&sevseg_four("1600").unwrap());
+///
+/// assert_eq!(display.lines().skip(5).collect::<Vec<_>>(), [
+///     "                 ▂▃▇▅▁▂",
+///     "                   ▴ ▾ ",
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sparkline {
+    values: VecDeque<f64>,
+    capacity: usize,
+    markers: bool,
+}
+
+impl Sparkline {
+    /// Empty history of at most `capacity` values with markers.
+    pub fn new(capacity: usize) -> Self {
+        Sparkline {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+            markers: true,
+        }
+    }
+
+    /// Sets whether the line of min and max markers is drawn.
+    pub fn markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Adds the newest value, dropping the oldest if the history is full.
+    pub fn push(&mut self, value: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// The values from the oldest to the newest.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values.iter().copied()
+    }
+
+    /// The sparkline of the newest values in a width, right aligned, with a blank for NaN and the
+    /// highest or lowest bar for an infinite value.
+    pub fn line(&self, width: usize) -> String {
+        self.lines(width).0
+    }
+
+    /// The display with the sparkline and the markers beneath at the width of the display.
+    pub fn render(&self, display: &str) -> String {
+        let width = display.lines().map(visible_width).max().unwrap_or(0);
+        let (line, markers) = self.lines(width);
+
+        let mut text = String::from(display);
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&line);
+        text.push('\n');
+        if self.markers {
+            text.push_str(&markers);
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Sparkline and markers of the newest values in a width.
+    fn lines(&self, width: usize) -> (String, String) {
+        let shown = self.values.len().min(width);
+        let values = self.values.range(self.values.len() - shown..);
+        let blanks = width - shown;
+
+        // Infinite values are drawn beyond the range of the finite ones.
+        let (min, max) = values
+            .clone()
+            .filter(|value| value.is_finite())
+            .fold(None, |range, &value| match range {
+                None => Some((value, value)),
+                Some((min, max)) => Some((value.min(min), value.max(max))),
+            })
+            .unwrap_or((0.0, 0.0));
+
+        let mut line = " ".repeat(blanks);
+        let mut markers = line.clone();
+        let newest_max = values.clone().rposition(|&value| value == max);
+        let newest_min = values.clone().rposition(|&value| value == min);
+        for (idx, &value) in values.enumerate() {
+            line.push(if value.is_nan() {
+                ' '
+            } else if value == f64::INFINITY {
+                LEVELS[LEVELS.len() - 1]
+            } else if value == f64::NEG_INFINITY {
+                LEVELS[0]
+            } else if max > min {
+                let level = (value - min) / (max - min) * (LEVELS.len() - 1) as f64;
+                LEVELS[(level + 0.5) as usize]
+            } else {
+                LEVELS[0]
+            });
+            markers.push(if max == min {
+                ' '
+            } else if Some(idx) == newest_max {
+                '▴'
+            } else if Some(idx) == newest_min {
+                '▾'
+            } else {
+                ' '
+            });
+        }
+
+        (line, markers)
+    }
+}
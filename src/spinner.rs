@@ -0,0 +1,147 @@
+//! Busy animations of segments.
+use crate::render::{write_cells, GlyphStyle};
+use crate::{DigitSize, Segments};
+use alloc::string::String;
+
+/// Animation of a [`Spinner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpinnerKind {
+    /// One outer segment of every digit turning clockwise.
+    Rotate,
+    /// One segment chasing clockwise around the outline of the whole display.
+    Chase,
+    /// Dashes waving up and down from the left to the right.
+    Wave,
+}
+
+/// Outer segments in clockwise order.
+const OUTER: [Segments; 6] = [
+    Segments::A,
+    Segments::B,
+    Segments::C,
+    Segments::D,
+    Segments::E,
+    Segments::F,
+];
+
+/// Segments of a wave digit in its phases.
+const WAVE: [Segments; 4] = [Segments::A, Segments::G, Segments::D, Segments::G];
+
+/// Endless iterator of the rendered frames of a busy animation.
+///
+/// The frames repeat after [`period`](Spinner::period) frames.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{Spinner, SpinnerKind};
+/// let mut spinner = Spinner::new(SpinnerKind::Rotate, 2);
+///
+/// assert_eq!(spinner.period(), 6);
+/// assert_eq!(spinner.next().unwrap(), "╺━━━╸ ╺━━━╸\n\
+///                                      \x20          \n\
+///                                      \x20          \n\
+///                                      \x20          \n\
+///                                      \x20          \n"
+/// );
+/// assert_eq!(spinner.next().unwrap(), "    ╻     ╻\n\
+///                                      \x20   ┃     ┃\n\
+///                                      \x20   ╹     ╹\n\
+///                                      \x20          \n\
+///                                      \x20          \n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinner {
+    kind: SpinnerKind,
+    width: usize,
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+    frame: usize,
+}
+
+impl Spinner {
+    /// Animation on a display of `width` digits.
+    pub fn new(kind: SpinnerKind, width: usize) -> Self {
+        Spinner {
+            kind,
+            width,
+            size: DigitSize::NORMAL,
+            glyph_style: GlyphStyle::Heavy,
+            frame: 0,
+        }
+    }
+
+    /// Sets the size of the digits.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters the digits are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// Number of frames before the animation repeats.
+    pub fn period(&self) -> usize {
+        match self.kind {
+            SpinnerKind::Rotate => OUTER.len(),
+            SpinnerKind::Chase => self.width * 2 + 4,
+            SpinnerKind::Wave => WAVE.len(),
+        }
+    }
+
+    /// Lit segments of a digit in a frame.
+    fn digit(&self, frame: usize, idx: usize) -> Segments {
+        match self.kind {
+            SpinnerKind::Rotate => OUTER[frame % OUTER.len()],
+            SpinnerKind::Chase => {
+                let last = self.width.saturating_sub(1);
+                let step = frame % self.period();
+                let (digit, segment) = match step {
+                    step if step < self.width => (step, Segments::A),
+                    step if step == self.width => (last, Segments::B),
+                    step if step == self.width + 1 => (last, Segments::C),
+                    step if step < self.width * 2 + 2 => {
+                        (last - (step - self.width - 2), Segments::D)
+                    }
+                    step if step == self.width * 2 + 2 => (0, Segments::E),
+                    _ => (0, Segments::F),
+                };
+
+                if digit == idx {
+                    segment
+                } else {
+                    Segments::NONE
+                }
+            }
+            SpinnerKind::Wave => WAVE[(frame + WAVE.len() - idx % WAVE.len()) % WAVE.len()],
+        }
+    }
+}
+
+impl Iterator for Spinner {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let frame = self.frame;
+        self.frame = (self.frame + 1) % self.period();
+
+        let mut text = String::new();
+        write_cells(
+            &mut text,
+            (0..self.width).map(|idx| (false, self.digit(frame, idx))),
+            self.size,
+            self.glyph_style,
+        )
+        .ok()?;
+
+        Some(text)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
@@ -0,0 +1,232 @@
+//! Animated change from one value to another.
+use crate::render::{write_cell_row, GlyphStyle};
+use crate::segments::text_segments;
+use crate::{DigitSize, Segments};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// How the changed digits of a [`Transition`] move to the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionEffect {
+    /// The old digit rolls up and the new digit rolls in from below, like an odometer.
+    Roll,
+    /// The segments of the old digit turn off one by one, then the segments of the new digit
+    /// turn on one by one.
+    Morph,
+    /// The segments turning off fade out as dim ghosts, then the segments turning on fade in.
+    Fade,
+}
+
+/// Iterator of the rendered frames of a change from an old to a new value.
+///
+/// The values can have the characters of a [`Marquee`](crate::Marquee), the shorter value is
+/// aligned to the right. Only the digits that change are animated, the last frame is the new
+/// value. Ghost segments of [`TransitionEffect::Fade`] are wrapped in the dim and normal
+/// intensity escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::{sevseg_four, Transition, TransitionEffect};
+/// let frames = Transition::new("1234", "1235")
+///     .unwrap()
+///     .effect(TransitionEffect::Roll)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(frames.len(), 5);
+/// assert_eq!(frames[1].lines().collect::<Vec<_>>(), [
+///     "    ╻ ╺━━━┓ ╺━━━┓ ┗━━━┫",
+///     "    ┃     ┃     ┃     ┃",
+///     "    ┃ ┏━━━┛ ╺━━━┫     ╹",
+///     "    ┃ ┃         ┃ ┏━━━╸",
+///     "    ╹ ┗━━━╸ ╺━━━┛ ┃    ",
+/// ]);
+/// assert_eq!(frames.last(), sevseg_four("1235").as_ref());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    from: Vec<Segments>,
+    to: Vec<Segments>,
+    effect: TransitionEffect,
+    steps: usize,
+    size: DigitSize,
+    glyph_style: GlyphStyle,
+    frame: usize,
+}
+
+impl Transition {
+    /// Transition from the old to the new value with the [`TransitionEffect::Morph`] effect.
+    ///
+    /// Returns `None` if a value has a character without a seven segment form.
+    pub fn new(from: &str, to: &str) -> Option<Self> {
+        let mut from = text_segments(from).collect::<Option<Vec<_>>>()?;
+        let mut to = text_segments(to).collect::<Option<Vec<_>>>()?;
+        let len = from.len().max(to.len());
+        for digits in [&mut from, &mut to] {
+            digits.splice(
+                ..0,
+                core::iter::repeat_n(Segments::NONE, len - digits.len()),
+            );
+        }
+
+        Some(Transition {
+            from,
+            to,
+            effect: TransitionEffect::Morph,
+            steps: 4,
+            size: DigitSize::NORMAL,
+            glyph_style: GlyphStyle::Heavy,
+            frame: 0,
+        })
+    }
+
+    /// Sets the effect.
+    pub fn effect(mut self, effect: TransitionEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Sets the number of frames before the new value, four by default.
+    pub fn steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Sets the size of the digits.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters the digits are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// Writes one line of a digit in a frame, the fraction `step / frames` of the way to the new
+    /// digit.
+    fn write_digit_row(&self, out: &mut String, idx: usize, row: usize, step: usize) {
+        let (from, to) = (self.from[idx], self.to[idx]);
+        let frames = self.steps + 1;
+        let width = self.size.columns()
+            + if from.contains(Segments::DP) || to.contains(Segments::DP) {
+                1
+            } else {
+                0
+            };
+        let start = out.len();
+        let mut ghosts = 0;
+
+        if from == to || step == frames {
+            let _ = write_cell_row(out, (false, to), self.size, self.glyph_style, row);
+        } else {
+            match self.effect {
+                TransitionEffect::Roll => {
+                    let rows = self.size.rows();
+                    let strip_row = row + step * rows / frames;
+                    let (segments, row) = if strip_row < rows {
+                        (from, strip_row)
+                    } else {
+                        (to, strip_row - rows)
+                    };
+                    let _ =
+                        write_cell_row(out, (false, segments), self.size, self.glyph_style, row);
+                }
+                TransitionEffect::Morph => {
+                    let bits =
+                        |set: u8| (0..8).map(|bit| 1 << bit).filter(move |bit| set & bit != 0);
+                    let (from_bits, to_bits) = (from.bits(), to.bits());
+                    let changes = bits(from_bits & !to_bits)
+                        .chain(bits(to_bits & !from_bits))
+                        .collect::<Vec<u8>>();
+                    let done = (step * changes.len()).div_ceil(frames);
+                    let segments = Segments::from_bits(
+                        changes[..done]
+                            .iter()
+                            .fold(from_bits, |bits, bit| bits ^ bit),
+                    );
+                    let _ =
+                        write_cell_row(out, (false, segments), self.size, self.glyph_style, row);
+                }
+                TransitionEffect::Fade => {
+                    let lit = Segments::from_bits(from.bits() & to.bits());
+                    let ghost = if step * 2 <= frames {
+                        Segments::from_bits(from.bits() & !to.bits())
+                    } else {
+                        Segments::from_bits(to.bits() & !from.bits())
+                    };
+                    ghosts = write_ghost_row(out, lit, ghost, self.size, self.glyph_style, row);
+                }
+            }
+        }
+
+        let written = out[start..].chars().count() - ghosts * GHOST_ESCAPES;
+        out.extend(core::iter::repeat_n(' ', width.saturating_sub(written)));
+    }
+}
+
+/// Characters of the escape sequences around a ghost segment character.
+const GHOST_ESCAPES: usize = 9;
+
+/// Writes one line of a digit with lit segments and dim ghost segments, returns the number of
+/// ghost characters.
+fn write_ghost_row(
+    out: &mut String,
+    lit: Segments,
+    ghost: Segments,
+    size: DigitSize,
+    style: GlyphStyle,
+    row: usize,
+) -> usize {
+    let mut lit_row = String::new();
+    let mut ghost_row = String::new();
+    let _ = write_cell_row(&mut lit_row, (false, lit), size, style, row);
+    let _ = write_cell_row(&mut ghost_row, (false, ghost | lit), size, style, row);
+    let mut lit_chars = lit_row.chars();
+    let mut ghosts = 0;
+
+    for ghost_chr in ghost_row.chars() {
+        match lit_chars.next().unwrap_or(' ') {
+            ' ' if ghost_chr != ' ' => {
+                let _ = write!(out, "\x1b[2m{}\x1b[22m", ghost_chr);
+                ghosts += 1;
+            }
+            chr => out.push(chr),
+        }
+    }
+
+    ghosts
+}
+
+impl Iterator for Transition {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.frame > self.steps {
+            return None;
+        }
+        self.frame += 1;
+
+        let mut text = String::new();
+        for row in 0..self.size.rows() {
+            for idx in 0..self.to.len() {
+                if idx > 0 {
+                    text.push(' ');
+                }
+                self.write_digit_row(&mut text, idx, row, self.frame);
+            }
+            text.push('\n');
+        }
+
+        Some(text)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.steps + 1).saturating_sub(self.frame);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Transition {}
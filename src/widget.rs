@@ -0,0 +1,198 @@
+//! Seven segment widget for `ratatui`.
+use crate::number::write_number_cells;
+use crate::render::{cells_columns, Cell, DigitSize, GlyphStyle};
+use crate::segments::str_segments;
+use crate::NumberStyle;
+use num::Bounded;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::Widget;
+
+/// Seven segment readout widget for `ratatui`.
+///
+/// Without a set size the widget uses the largest [`DigitSize`] that fits into its area.
+///
+/// # Examples
+///
+/// ```
+/// # use seven_seg::SevenSegment;
+/// # use ratatui::{buffer::Buffer, layout::{Alignment, Rect}, widgets::Widget};
+/// let area = Rect::new(0, 0, 12, 5);
+/// let mut buf = Buffer::empty(area);
+///
+/// SevenSegment::new("42")
+///     .unwrap()
+///     .alignment(Alignment::Right)
+///     .render(area, &mut buf);
+///
+/// assert_eq!(buf, Buffer::with_lines([
+///     " ╻   ╻ ╺━━━┓",
+///     " ┃   ┃     ┃",
+///     " ┗━━━┫ ┏━━━┛",
+///     "     ┃ ┃    ",
+///     "     ╹ ┗━━━╸",
+/// ]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SevenSegment {
+    cells: Vec<Cell>,
+    groups: Vec<usize>,
+    number_style: NumberStyle,
+    size: Option<DigitSize>,
+    glyph_style: GlyphStyle,
+    style: Style,
+    segment_style: Style,
+    alignment: Alignment,
+}
+
+impl SevenSegment {
+    /// Widget of digits from zero to nine, dashes and spaces, a point sets the decimal point of
+    /// the digit before.
+    ///
+    /// Returns `None` if the text has any other character.
+    pub fn new(text: &str) -> Option<Self> {
+        let cells = str_segments(text)
+            .map(|segments| segments.map(|segments| (false, segments)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(SevenSegment::from_cells(cells))
+    }
+
+    /// Widget of the four digits with decimal point and merged minus sign of
+    /// [`sevseg_four_dp`](crate::sevseg_four_dp).
+    pub fn from_number<T>(n: T) -> Self
+    where
+        T: std::fmt::Display + Bounded,
+    {
+        SevenSegment::from_number_with(n, NumberStyle::new())
+    }
+
+    /// Widget of the four digits with decimal point of
+    /// [`sevseg_four_dp_with`](crate::sevseg_four_dp_with) in a number style.
+    pub fn from_number_with<T: std::fmt::Display>(n: T, style: NumberStyle) -> Self {
+        let mut cells = style.four_cells(&n.to_string());
+        let groups = style.group_cells(&mut cells);
+
+        let mut widget = SevenSegment::from_cells(cells.to_vec());
+        widget.number_style = style;
+        widget.groups = groups;
+        widget
+    }
+
+    fn from_cells(cells: Vec<Cell>) -> Self {
+        SevenSegment {
+            cells,
+            groups: Vec::new(),
+            number_style: NumberStyle::new(),
+            size: None,
+            glyph_style: GlyphStyle::Heavy,
+            style: Style::default(),
+            segment_style: Style::default(),
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Sets a fixed size of the digits.
+    pub fn size(mut self, size: DigitSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the characters the digits are drawn with.
+    pub fn glyph_style(mut self, glyph_style: GlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+        self
+    }
+
+    /// Sets the style of the whole area of the widget.
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the lit segments.
+    pub fn segment_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.segment_style = style.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the digits in the area.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Columns and lines the digits take at a size.
+    pub fn dimensions(&self, size: DigitSize) -> (u16, u16) {
+        let columns = self.number_style.sign.blank_columns(&self.cells, size)
+            + cells_columns(self.cells.iter().copied(), size)
+            + self.groups.len();
+
+        (
+            u16::try_from(columns).unwrap_or(u16::MAX),
+            size.rows() as u16,
+        )
+    }
+
+    fn fitting_size(&self, area: Rect) -> DigitSize {
+        DigitSize::ALL
+            .into_iter()
+            .rev()
+            .find(|&size| {
+                let (width, height) = self.dimensions(size);
+                width <= area.width && height <= area.height
+            })
+            .unwrap_or(DigitSize::SMALL)
+    }
+}
+
+impl Widget for SevenSegment {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &SevenSegment {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+
+        let size = self.size.unwrap_or_else(|| self.fitting_size(area));
+        let mut text = String::new();
+        if write_number_cells(
+            &mut text,
+            &self.cells,
+            &self.groups,
+            size,
+            self.glyph_style,
+            self.number_style,
+        )
+        .is_err()
+        {
+            return;
+        }
+
+        let width = self.dimensions(size).0;
+        let left = area.x
+            + match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => area.width.saturating_sub(width) / 2,
+                Alignment::Right => area.width.saturating_sub(width),
+            };
+
+        for (y, line) in (area.y..area.bottom()).zip(text.lines()) {
+            for (x, chr) in (left..area.right()).zip(line.chars()) {
+                if chr == ' ' {
+                    continue;
+                }
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(chr).set_style(self.segment_style);
+                }
+            }
+        }
+    }
+}
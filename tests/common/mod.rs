@@ -0,0 +1,16 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+use seven_seg::{sevseg_render_with, DigitSize, GlyphStyle};
+
+/// The text rendered by `sevseg_render`.
+pub fn render(text: &str) -> String {
+    render_with(text, DigitSize::NORMAL, GlyphStyle::Heavy)
+}
+
+/// The text rendered by `sevseg_render_with` in a buffer large enough for any test display.
+pub fn render_with(text: &str, size: DigitSize, style: GlyphStyle) -> String {
+    let mut buf = vec![0; 1 << 16];
+    sevseg_render_with(text, size, style, &mut buf)
+        .unwrap()
+        .to_string()
+}
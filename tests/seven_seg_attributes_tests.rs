@@ -0,0 +1,83 @@
+use seven_seg::*;
+
+#[test]
+fn attributes_none_same_as_render() {
+    let mut buf = [0; 512];
+    let digits = AttributedDigits::new("12.34").unwrap();
+
+    assert_eq!(digits.render(), sevseg_render("12.34", &mut buf).unwrap());
+    let [on, off] = digits.blink_frames();
+    assert_eq!(on, off);
+}
+
+#[test]
+fn attributes_sgr_codes() {
+    let digits = AttributedDigits::new("8")
+        .unwrap()
+        .attributes(0, Attributes::BLINK | Attributes::INVERT | Attributes::DIM);
+
+    for line in digits.render().lines() {
+        assert!(line.starts_with("\x1b[2;5;7m"));
+        assert!(line.ends_with("\x1b[0m"));
+    }
+}
+
+#[test]
+fn attributes_blink_frames_keep_invert_and_dim() {
+    let digits = AttributedDigits::new("18")
+        .unwrap()
+        .attributes(0, Attributes::DIM)
+        .attributes(1, Attributes::BLINK | Attributes::INVERT);
+    let [on, off] = digits.blink_frames();
+
+    assert_eq!(
+        on.lines().next().unwrap(),
+        "\x1b[2m    ╻\x1b[0m \x1b[7m┏━━━┓\x1b[0m"
+    );
+    assert_eq!(
+        off.lines().next().unwrap(),
+        "\x1b[2m    ╻\x1b[0m \x1b[7m     \x1b[0m"
+    );
+    assert!(!on.contains("\x1b[5"));
+}
+
+#[test]
+fn attributes_blink_blank_keeps_width() {
+    let digits = AttributedDigits::new("1.2")
+        .unwrap()
+        .attributes(0, Attributes::BLINK)
+        .size(DigitSize::LARGE);
+    let [on, off] = digits.blink_frames();
+
+    for (on, off) in on.lines().zip(off.lines()) {
+        assert_eq!(on.chars().count(), off.chars().count());
+    }
+    assert!(off.lines().all(|line| line.starts_with("        ")));
+}
+
+#[test]
+fn attributes_set_and_out_of_range() {
+    let mut digits = AttributedDigits::new("12").unwrap();
+    digits.set_attributes(5, Attributes::BLINK);
+    assert_eq!(digits, AttributedDigits::new("12").unwrap());
+
+    digits.set_attributes(1, Attributes::BLINK);
+    digits.set_attributes(1, Attributes::NONE);
+    assert_eq!(digits, AttributedDigits::new("12").unwrap());
+}
+
+#[test]
+fn attributes_flags() {
+    let mut attributes = Attributes::NONE;
+    assert!(attributes.is_empty());
+
+    attributes |= Attributes::DIM;
+    assert!(attributes.contains(Attributes::DIM));
+    assert!(!attributes.contains(Attributes::DIM | Attributes::BLINK));
+    assert_eq!(Attributes::default(), Attributes::NONE);
+}
+
+#[test]
+fn attributes_invalid_text() {
+    assert!(AttributedDigits::new("12:30").is_none());
+}
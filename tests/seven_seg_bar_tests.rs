@@ -0,0 +1,93 @@
+use seven_seg::*;
+
+#[test]
+fn bar_lit_segments() {
+    let bar = BarGraph::new(10);
+
+    assert_eq!(bar.lit_segments(0.0), 0);
+    assert_eq!(bar.lit_segments(44.0), 4);
+    assert_eq!(bar.lit_segments(45.0), 5);
+    assert_eq!(bar.lit_segments(100.0), 10);
+    assert_eq!(bar.lit_segments(-20.0), 0);
+    assert_eq!(bar.lit_segments(250.0), 10);
+    assert_eq!(bar.lit_segments(f64::NAN), 0);
+    assert_eq!(bar.lit_segments(f64::INFINITY), 10);
+
+    let bar = BarGraph::new(4).range(-40.0, 40.0);
+    assert_eq!(bar.lit_segments(0.0), 2);
+    assert_eq!(BarGraph::new(4).range(5.0, 5.0).lit_segments(5.0), 4);
+}
+
+#[test]
+fn bar_horizontal_is_as_high_as_the_digits() {
+    let bar = BarGraph::new(3).glyph_style(GlyphStyle::Ascii);
+
+    assert_eq!(bar.render(50.0), "| | :\n".repeat(5));
+    assert_eq!(bar.size(DigitSize::HUGE).render(50.0).lines().count(), 9);
+}
+
+#[test]
+fn bar_vertical_lights_from_the_bottom() {
+    let bar = BarGraph::new(3)
+        .direction(BarDirection::Vertical)
+        .glyph_style(GlyphStyle::Light);
+
+    assert_eq!(
+        bar.render(70.0).lines().collect::<Vec<_>>(),
+        ["   ", "   ", "╌╌╌", "───", "───"]
+    );
+}
+
+#[test]
+fn bar_vertical_is_as_high_as_the_digits() {
+    let tall = BarGraph::new(8).direction(BarDirection::Vertical);
+
+    assert_eq!(tall.lit_segments(60.0), 3);
+    assert_eq!(tall.render(0.0), "╍╍╍\n".repeat(5));
+    assert_eq!(tall.render(60.0), "╍╍╍\n╍╍╍\n━━━\n━━━\n━━━\n");
+    assert_eq!(
+        tall.clone().size(DigitSize::LARGE).render(100.0),
+        "━━━━━\n".repeat(7)
+    );
+    assert_eq!(
+        tall.direction(BarDirection::Horizontal).lit_segments(60.0),
+        5
+    );
+}
+
+#[test]
+fn bar_threshold_colors() {
+    let bar = BarGraph::new(5)
+        .threshold(80.0, 31)
+        .threshold(40.0, 33)
+        .glyph_style(GlyphStyle::Ascii);
+
+    assert_eq!(
+        bar.render(60.0).lines().next(),
+        Some("| | \x1b[33m| : \x1b[31m:\x1b[0m")
+    );
+
+    let vertical = bar.clone().color(32).direction(BarDirection::Vertical);
+    assert_eq!(
+        vertical.render(100.0).lines().collect::<Vec<_>>(),
+        [
+            "\x1b[31m===\x1b[0m",
+            "\x1b[33m===\x1b[0m",
+            "\x1b[33m===\x1b[0m",
+            "\x1b[32m===\x1b[0m",
+            "\x1b[32m===\x1b[0m",
+        ]
+    );
+}
+
+#[test]
+fn bar_joins_beside_the_digits() {
+    let digits = sevseg_four_dp(75);
+    let bar = BarGraph::new(4).color(32).render(75.0);
+    let display = cattocol::by_lines(&digits, &bar).collect::<String>();
+
+    assert_eq!(display.lines().count(), 5);
+    for ((line, digits_line), bar_line) in display.lines().zip(digits.lines()).zip(bar.lines()) {
+        assert_eq!(line, format!("{} {}", digits_line, bar_line));
+    }
+}
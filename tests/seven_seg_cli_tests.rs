@@ -0,0 +1,175 @@
+#![cfg(feature = "cli")]
+mod common;
+
+use common::render_with;
+use seven_seg::*;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn sevseg(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sevseg"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], stdin: &str) -> String {
+    let output = sevseg(args, stdin);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn cli_argument() {
+    assert_eq!(stdout(&["8023"], ""), sevseg_four("8023").unwrap());
+    assert_eq!(
+        stdout(&["12", "34"], ""),
+        render_with("12 34", DigitSize::NORMAL, GlyphStyle::Heavy)
+    );
+}
+
+#[test]
+fn cli_stdin_lines() {
+    assert_eq!(
+        stdout(&[], "1\n-2\n"),
+        render_with("1", DigitSize::NORMAL, GlyphStyle::Heavy)
+            + &render_with("-2", DigitSize::NORMAL, GlyphStyle::Heavy)
+    );
+}
+
+#[test]
+fn cli_negative_argument() {
+    assert_eq!(
+        stdout(&["-12"], ""),
+        render_with("-12", DigitSize::NORMAL, GlyphStyle::Heavy)
+    );
+}
+
+#[test]
+fn cli_style_and_size() {
+    assert_eq!(
+        stdout(&["--style", "ascii", "-z", "large", "42"], ""),
+        render_with("42", DigitSize::LARGE, GlyphStyle::Ascii)
+    );
+    assert_eq!(
+        stdout(&["--style=light", "--size=small", "7"], ""),
+        render_with("7", DigitSize::SMALL, GlyphStyle::Light)
+    );
+}
+
+#[test]
+fn cli_digits_and_alignment() {
+    let heavy = |string| render_with(string, DigitSize::NORMAL, GlyphStyle::Heavy);
+
+    assert_eq!(stdout(&["-n", "4", "7"], ""), heavy("   7"));
+    assert_eq!(stdout(&["-n", "4", "-a", "left", "7"], ""), heavy("7   "));
+    assert_eq!(stdout(&["-n", "4", "-a", "center", "7"], ""), heavy(" 7  "));
+    assert_eq!(stdout(&["-n", "3", "1.5"], ""), heavy(" 1.5"));
+}
+
+#[test]
+fn cli_decimals() {
+    assert_eq!(
+        stdout(&["-p", "2", "3.14159"], ""),
+        render_with("3.14", DigitSize::NORMAL, GlyphStyle::Heavy)
+    );
+    assert_eq!(
+        stdout(&["-p", "0", "-n", "2"], "7.6\n"),
+        render_with(" 8", DigitSize::NORMAL, GlyphStyle::Heavy)
+    );
+}
+
+#[test]
+fn cli_color() {
+    let output = stdout(&["-c", "green", "1"], "");
+
+    assert_eq!(output.lines().count(), 5);
+    for line in output.lines() {
+        assert!(line.starts_with("\x1b[32m"));
+        assert!(line.ends_with("\x1b[0m"));
+    }
+}
+
+#[test]
+fn cli_errors() {
+    for args in [
+        &["12a"][..],
+        &["-n", "2", "123"],
+        &["-p", "1", "abc"],
+        &["--style", "round", "1"],
+        &["--bogus"],
+        &["-n"],
+    ] {
+        let output = sevseg(args, "");
+        assert!(!output.status.success(), "{:?}", args);
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("sevseg: "));
+    }
+}
+
+#[test]
+fn cli_help_and_version() {
+    assert!(stdout(&["--help"], "").starts_with("Usage: sevseg"));
+    assert_eq!(
+        stdout(&["-V"], ""),
+        format!("sevseg {}\n", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn cli_full_screen_command_errors() {
+    for (args, error) in [
+        (&["timer"][..], "sevseg: missing duration of timer\n"),
+        (&["timer", "5x"], "sevseg: invalid duration 5x\n"),
+        (&["timer", "1:2:3:4"], "sevseg: invalid duration 1:2:3:4\n"),
+        (&["timer", "0s"], "sevseg: invalid duration 0s\n"),
+        (
+            &["timer", "99999999999999999h"],
+            "sevseg: invalid duration 99999999999999999h\n",
+        ),
+        (
+            &["timer", "9999999999999999999:0"],
+            "sevseg: invalid duration 9999999999999999999:0\n",
+        ),
+        (
+            &["timer", "18446744073709551615s1"],
+            "sevseg: invalid duration 18446744073709551615s1\n",
+        ),
+        (&["clock", "now"], "sevseg: unexpected argument now\n"),
+        (&["stopwatch", "1"], "sevseg: unexpected argument 1\n"),
+    ] {
+        let output = sevseg(args, "");
+        assert!(!output.status.success(), "{:?}", args);
+        assert_eq!(String::from_utf8(output.stderr).unwrap(), error);
+    }
+}
+
+#[test]
+fn cli_help_lists_commands() {
+    let help = stdout(&["-h"], "");
+    for command in ["clock", "timer <DURATION>", "stopwatch"] {
+        assert!(help.contains(command), "{}", command);
+    }
+}
+
+#[test]
+fn cli_ascii_glyphs() {
+    assert_eq!(
+        stdout(&["--style", "ascii", "7"], "")
+            .lines()
+            .collect::<Vec<_>>(),
+        ["----+", "    |", "    |", "    |", "    |",]
+    );
+}
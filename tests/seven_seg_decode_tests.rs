@@ -0,0 +1,149 @@
+mod common;
+
+use common::render_with;
+use seven_seg::*;
+
+#[test]
+fn sevseg_decode_four() {
+    for string in ["0123", "4567", "89--", "0001", "7"] {
+        let decoded = sevseg_decode(&sevseg_four(string).unwrap()).unwrap();
+        assert_eq!(decoded.string, format!("{:0>4}", string));
+        assert!(decoded.points.is_empty());
+        assert!(!decoded.negative);
+        assert!(decoded.unrecognized.is_empty());
+    }
+}
+
+#[test]
+fn sevseg_decode_one_two_three() {
+    assert_eq!(
+        sevseg_decode(&sevseg_one("1").unwrap()).unwrap().string,
+        "1"
+    );
+    assert_eq!(
+        sevseg_decode(&sevseg_two("-7").unwrap()).unwrap().string,
+        "-7"
+    );
+    assert_eq!(
+        sevseg_decode(&sevseg_three("71").unwrap()).unwrap().string,
+        "071"
+    );
+}
+
+#[test]
+fn sevseg_decode_dp() {
+    let decoded = sevseg_decode(&sevseg_four_dp(12.5_f64)).unwrap();
+    assert_eq!(decoded.string, "1250");
+    assert_eq!(decoded.points, [1]);
+    assert_eq!(decoded.to_string(), "12.50");
+
+    let decoded = sevseg_decode(&sevseg_four_dp(8023_u16)).unwrap();
+    assert_eq!(decoded.to_string(), "8023.");
+
+    let decoded = sevseg_decode(&sevseg_four_dp(80234_u32)).unwrap();
+    assert_eq!(decoded.to_string(), "8023");
+}
+
+#[test]
+fn sevseg_decode_dp_negative() {
+    for (n, shown) in [
+        (-1.0, "-1.000"),
+        (-12.0, "-12.00"),
+        (-7.25, "-7.250"),
+        (-0.5, "-0.500"),
+        (-2345.0, "-2345."),
+        (-123456.789, "-1234"),
+    ] {
+        assert_eq!(
+            sevseg_decode(&sevseg_four_dp(n)).unwrap().to_string(),
+            shown
+        );
+    }
+}
+
+#[test]
+fn sevseg_decode_dp_all_digits_negative() {
+    for digit in 0..10_i8 {
+        let decoded = sevseg_decode(&sevseg_four_dp(-digit - 10)).unwrap();
+        assert!(decoded.negative, "{}", digit);
+        assert_eq!(decoded.string, format!("1{}00", digit));
+        assert_eq!(decoded.points, [1]);
+    }
+}
+
+#[test]
+fn sevseg_decode_unrecognized() {
+    let text = "┏━━━┓ ╺━━━╸\n\
+                ┃   ┃      \n\
+                ┣━━━┫ ╺━━━┓\n\
+                ┃   ┃     ┃\n\
+                ┗━━━┛     ╹\n";
+    let decoded = sevseg_decode(text).unwrap();
+    assert_eq!(decoded.string, "8?");
+    assert_eq!(decoded.unrecognized, [1]);
+}
+
+#[test]
+fn sevseg_decode_not_a_display() {
+    assert_eq!(sevseg_decode(""), None);
+    assert_eq!(sevseg_decode("8023\n"), None);
+    assert_eq!(sevseg_decode(&"not a display\n".repeat(5)), None);
+
+    let line = "┃━┏┓┗┛╻╹ ".chars().cycle().take(120).collect::<String>();
+    assert_eq!(sevseg_decode(&format!("{}\n", line).repeat(5)), None);
+}
+
+#[test]
+fn sevseg_decode_every_style_and_size() {
+    let sizes = DigitSize::ALL
+        .into_iter()
+        .chain([DigitSize::new(4, 1), DigitSize::new(2, 4)]);
+
+    for size in sizes {
+        for style in [GlyphStyle::Heavy, GlyphStyle::Light, GlyphStyle::Ascii] {
+            for (text, string, points) in [
+                ("-0123.4567", "-01234567", &[4][..]),
+                ("-1", "-1", &[]),
+                ("89.", "89", &[1]),
+                (" 7-", " 7-", &[]),
+                ("8.8.8.", "888", &[0, 1, 2]),
+            ] {
+                let decoded = sevseg_decode(&render_with(text, size, style)).unwrap();
+                let case = format!("{:?} {:?} {:?}", text, size, style);
+
+                assert_eq!(decoded.string, string, "{}", case);
+                assert_eq!(decoded.points, points, "{}", case);
+                assert!(!decoded.negative, "{}", case);
+                assert!(decoded.unrecognized.is_empty(), "{}", case);
+            }
+        }
+    }
+}
+
+#[test]
+fn sevseg_decode_ascii_dash_is_not_a_minus() {
+    let ascii = render_with("-1", DigitSize::NORMAL, GlyphStyle::Ascii);
+    assert_eq!(
+        ascii.lines().collect::<Vec<_>>(),
+        [
+            "          |",
+            "          |",
+            "-----     |",
+            "          |",
+            "          |",
+        ]
+    );
+    assert_eq!(sevseg_decode(&ascii).unwrap().to_string(), "-1");
+}
+
+#[test]
+fn sevseg_decode_letters_and_labels() {
+    let decoded = sevseg_decode(&sevseg_si(1500, 2, PrefixStyle::Digit).unwrap()).unwrap();
+    assert_eq!(decoded.to_string(), "1.5?");
+    assert_eq!(decoded.unrecognized, [2]);
+
+    assert_eq!(
+        sevseg_decode(&sevseg_si(1500, 2, PrefixStyle::Label).unwrap()),
+        None
+    );
+}
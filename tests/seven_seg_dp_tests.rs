@@ -1,4 +1,4 @@
-use seven_seg::sevseg_four_dp;
+use seven_seg::{sevseg_four, sevseg_four_dp, sevseg_four_dp_with, NonFinite, NumberStyle};
 
 #[test]
 fn sevseg_dp_neg_point_pos_three_zero() {
@@ -203,8 +203,9 @@ fn sevseg_dp_point_pos_six_zero() {
 #[test]
 fn sevseg_dp_point_pos_three() {
     let val_dp: f32 = -5.1000;
-    assert_eq!(sevseg_four_dp(val_dp),
-    "  ┏━━━╸      ╻ ┏━━━┓ ┏━━━┓\n  ┃          ┃ ┃   ┃ ┃   ┃\n\
+    assert_eq!(
+        sevseg_four_dp(val_dp),
+        "  ┏━━━╸      ╻ ┏━━━┓ ┏━━━┓\n  ┃          ┃ ┃   ┃ ┃   ┃\n\
     ━━┗━━━┓      ┃ ┃   ┃ ┃   ┃\n      ┃      ┃ ┃   ┃ ┃   ┃\n  ╺━━━┛⦁     ╹ ┗━━━┛ ┗━━━┛\n"
     );
 }
@@ -212,8 +213,9 @@ fn sevseg_dp_point_pos_three() {
 #[test]
 fn sevseg_dp_point_pos_four() {
     let val_dp: f32 = -51.693;
-    assert_eq!(sevseg_four_dp(val_dp),
-    "  ┏━━━╸     ╻  ┏━━━╸ ┏━━━┓\n  ┃         ┃  ┃     ┃   ┃\n\
+    assert_eq!(
+        sevseg_four_dp(val_dp),
+        "  ┏━━━╸     ╻  ┏━━━╸ ┏━━━┓\n  ┃         ┃  ┃     ┃   ┃\n\
     ━━┗━━━┓     ┃  ┣━━━┓ ┗━━━┫\n      ┃     ┃  ┃   ┃     ┃\n  ╺━━━┛     ╹⦁ ┗━━━┛ ╺━━━┛\n"
     );
 }
@@ -221,8 +223,9 @@ fn sevseg_dp_point_pos_four() {
 #[test]
 fn sevseg_dp_point_pos_five() {
     let val_dp: f32 = -516.93;
-    assert_eq!(sevseg_four_dp(val_dp),
-    "  ┏━━━╸     ╻ ┏━━━╸  ┏━━━┓\n  ┃         ┃ ┃      ┃   ┃\n\
+    assert_eq!(
+        sevseg_four_dp(val_dp),
+        "  ┏━━━╸     ╻ ┏━━━╸  ┏━━━┓\n  ┃         ┃ ┃      ┃   ┃\n\
     ━━┗━━━┓     ┃ ┣━━━┓  ┗━━━┫\n      ┃     ┃ ┃   ┃      ┃\n  ╺━━━┛     ╹ ┗━━━┛⦁ ╺━━━┛\n"
     );
 }
@@ -230,8 +233,9 @@ fn sevseg_dp_point_pos_five() {
 #[test]
 fn sevseg_dp_point_pos_six() {
     let val_dp: f32 = -5169.3;
-    assert_eq!(sevseg_four_dp(val_dp),
-    "  ┏━━━╸     ╻ ┏━━━╸ ┏━━━┓ \n  ┃         ┃ ┃     ┃   ┃ \n\
+    assert_eq!(
+        sevseg_four_dp(val_dp),
+        "  ┏━━━╸     ╻ ┏━━━╸ ┏━━━┓ \n  ┃         ┃ ┃     ┃   ┃ \n\
     ━━┗━━━┓     ┃ ┣━━━┓ ┗━━━┫ \n      ┃     ┃ ┃   ┃     ┃ \n  ╺━━━┛     ╹ ┗━━━┛ ╺━━━┛⦁\n"
     );
 }
@@ -244,7 +248,6 @@ fn sevseg_dp_point_pos_two_no_digits() {
     );
 }
 
-
 #[test]
 fn sevseg_dp_point_pos_two_positive() {
     let val_dp: f64 = 1.23456789;
@@ -263,7 +266,7 @@ fn sevseg_dp_point_pos_three_psitive() {
 
 #[test]
 fn sevseg_dp_point_pos_four_psitive() {
-let val_dp: f64 = 562.0421;
+    let val_dp: f64 = 562.0421;
     assert_eq!(sevseg_four_dp(val_dp),
     "┏━━━╸ ┏━━━╸ ╺━━━┓  ┏━━━┓\n┃     ┃         ┃  ┃   ┃\n┗━━━┓ ┣━━━┓ ┏━━━┛  ┃   ┃\n    ┃ ┃   ┃ ┃      ┃   ┃\n╺━━━┛ ┗━━━┛ ┗━━━╸⦁ ┗━━━┛\n"
     );
@@ -271,7 +274,7 @@ let val_dp: f64 = 562.0421;
 
 #[test]
 fn sevseg_dp_point_pos_six_psitive() {
-let val_dp: f64 = 5620.421;
+    let val_dp: f64 = 5620.421;
     assert_eq!(sevseg_four_dp(val_dp),
     "┏━━━╸ ┏━━━╸ ╺━━━┓ ┏━━━┓ \n┃     ┃         ┃ ┃   ┃ \n┗━━━┓ ┣━━━┓ ┏━━━┛ ┃   ┃ \n    ┃ ┃   ┃ ┃     ┃   ┃ \n╺━━━┛ ┗━━━┛ ┗━━━╸ ┗━━━┛⦁\n"
     );
@@ -279,8 +282,107 @@ let val_dp: f64 = 5620.421;
 
 #[test]
 fn sevseg_dp_point_pos_seven_psitive() {
-let val_dp: f64 = 56204.21;
+    let val_dp: f64 = 56204.21;
     assert_eq!(sevseg_four_dp(val_dp),
     "┏━━━╸ ┏━━━╸ ╺━━━┓ ┏━━━┓\n┃     ┃         ┃ ┃   ┃\n┗━━━┓ ┣━━━┓ ┏━━━┛ ┃   ┃\n    ┃ ┃   ┃ ┃     ┃   ┃\n╺━━━┛ ┗━━━┛ ┗━━━╸ ┗━━━┛\n"
     );
 }
+
+#[test]
+fn sevseg_dp_nan_letters() {
+    let nan = sevseg_four_dp(f64::NAN);
+
+    assert_eq!(
+        nan.lines().collect::<Vec<_>>(),
+        [
+            "            ┏━━━┓      ",
+            "            ┃   ┃      ",
+            "      ┏━━━┓ ┣━━━┫ ┏━━━┓",
+            "      ┃   ┃ ┃   ┃ ┃   ┃",
+            "      ╹   ╹ ╹   ╹ ╹   ╹",
+        ]
+    );
+    assert_eq!(sevseg_four_dp(f32::NAN), nan);
+}
+
+#[test]
+fn sevseg_dp_infinity_letters() {
+    let neg_infinity = sevseg_four_dp(f64::NEG_INFINITY);
+
+    assert_eq!(
+        neg_infinity.lines().collect::<Vec<_>>(),
+        [
+            "      ╻           ┏━━━╸",
+            "      ┃           ┃    ",
+            "╺━━━╸ ┃     ┏━━━┓ ┣━━━╸",
+            "      ┃     ┃   ┃ ┃    ",
+            "      ╹     ╹   ╹ ╹    ",
+        ]
+    );
+    assert_eq!(sevseg_four_dp(f32::NEG_INFINITY), neg_infinity);
+    assert_eq!(
+        sevseg_four_dp(f32::INFINITY),
+        neg_infinity.replacen("╺━━━╸", "     ", 1)
+    );
+    assert_eq!(sevseg_four_dp(f64::INFINITY), sevseg_four_dp(f32::INFINITY));
+}
+
+#[test]
+fn sevseg_dp_non_finite_dashes() {
+    let dashes = sevseg_four("----").unwrap();
+
+    for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            sevseg_four_dp_with(n, NumberStyle::new().non_finite(NonFinite::Dashes)),
+            dashes
+        );
+        assert_eq!(
+            sevseg_four_dp_with(n as f32, NumberStyle::new().non_finite(NonFinite::Dashes)),
+            dashes
+        );
+    }
+}
+
+#[test]
+fn sevseg_dp_non_finite_error() {
+    let error = sevseg_four_dp_with(f64::NAN, NumberStyle::new().non_finite(NonFinite::Error));
+
+    assert_eq!(
+        error.lines().collect::<Vec<_>>(),
+        [
+            "      ┏━━━╸            ",
+            "      ┃                ",
+            "      ┣━━━╸ ┏━━━╸ ┏━━━╸",
+            "      ┃     ┃     ┃    ",
+            "      ┗━━━╸ ╹     ╹    ",
+        ]
+    );
+    assert_eq!(
+        sevseg_four_dp_with(
+            f32::INFINITY,
+            NumberStyle::new().non_finite(NonFinite::Error)
+        ),
+        error
+    );
+    assert_eq!(
+        sevseg_four_dp_with(
+            f32::NEG_INFINITY,
+            NumberStyle::new().non_finite(NonFinite::Error)
+        ),
+        error
+    );
+}
+
+#[test]
+fn sevseg_dp_finite_ignores_non_finite() {
+    for n in [-1.5_f64, 0.0, 12.25, 8023.0] {
+        assert_eq!(
+            sevseg_four_dp_with(n, NumberStyle::new().non_finite(NonFinite::Error)),
+            sevseg_four_dp(n)
+        );
+        assert_eq!(
+            sevseg_four_dp_with(n as f32, NumberStyle::new().non_finite(NonFinite::Dashes)),
+            sevseg_four_dp(n as f32)
+        );
+    }
+}
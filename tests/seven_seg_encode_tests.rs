@@ -0,0 +1,77 @@
+use seven_seg::{sevseg_encode, SegmentMap, Segments};
+
+#[test]
+fn sevseg_encode_tm1637_digits() {
+    assert_eq!(
+        sevseg_encode("0123456789", &SegmentMap::TM1637),
+        Some(vec![
+            0x3f, 0x06, 0x5b, 0x4f, 0x66, 0x6d, 0x7d, 0x07, 0x7f, 0x6f
+        ])
+    );
+}
+
+#[test]
+fn sevseg_encode_max7219_digits() {
+    assert_eq!(
+        sevseg_encode("0123456789", &SegmentMap::MAX7219),
+        Some(vec![
+            0x7e, 0x30, 0x6d, 0x79, 0x33, 0x5b, 0x5f, 0x70, 0x7f, 0x7b
+        ])
+    );
+}
+
+#[test]
+fn sevseg_encode_max7219_dp() {
+    assert_eq!(
+        sevseg_encode("8.8.", &SegmentMap::MAX7219),
+        Some(vec![0xff, 0xff])
+    );
+}
+
+#[test]
+fn sevseg_encode_ht16k33() {
+    assert_eq!(
+        sevseg_encode("1.5", &SegmentMap::HT16K33),
+        Some(vec![0x86, 0x6d])
+    );
+}
+
+#[test]
+fn sevseg_encode_hc595() {
+    assert_eq!(
+        sevseg_encode("8 -", &SegmentMap::HC595_COMMON_CATHODE),
+        Some(vec![0x7f, 0x00, 0x40])
+    );
+    assert_eq!(
+        sevseg_encode("8 -", &SegmentMap::HC595_COMMON_ANODE),
+        Some(vec![0x80, 0xff, 0xbf])
+    );
+}
+
+#[test]
+fn sevseg_encode_points() {
+    assert_eq!(
+        sevseg_encode(".1..", &SegmentMap::TM1637),
+        Some(vec![0x80, 0x86, 0x80])
+    );
+}
+
+#[test]
+fn sevseg_encode_custom_map() {
+    let map = SegmentMap::new([1, 2, 3, 4, 5, 6, 7, 0]);
+    assert_eq!(map.encode(Segments::B | Segments::C), 0b0000_1100);
+    assert_eq!(map.encode(Segments::DP), 0b0000_0001);
+    assert_eq!(sevseg_encode("7", &map), Some(vec![0b0000_1110]));
+}
+
+#[test]
+fn sevseg_encode_invalid() {
+    assert_eq!(sevseg_encode("12a", &SegmentMap::TM1637), None);
+    assert_eq!(sevseg_encode("", &SegmentMap::TM1637), Some(vec![]));
+}
+
+#[test]
+#[should_panic]
+fn segment_map_bit_out_of_range() {
+    SegmentMap::new([0, 1, 2, 3, 4, 5, 6, 8]);
+}
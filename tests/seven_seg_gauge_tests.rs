@@ -0,0 +1,73 @@
+use seven_seg::*;
+
+/// Lit ticks of an ASCII ring, the digits are drawn with heavy lines.
+fn lit_ticks(gauge: &str) -> usize {
+    gauge.chars().filter(|chr| matches!(chr, '|' | '=')).count()
+}
+
+#[test]
+fn gauge_centers_the_value() {
+    let gauge = Gauge::new(0.0, 10.0).render(2.5).unwrap();
+    let display = sevseg_number(&2.5, 4).unwrap();
+
+    assert_eq!(gauge.lines().count(), display.lines().count() + 1);
+    for (line, digits) in gauge.lines().skip(1).zip(display.lines()) {
+        assert_eq!(
+            line.chars().skip(2).collect::<String>(),
+            format!("{} ╏", digits)
+        );
+    }
+}
+
+#[test]
+fn gauge_lights_the_fraction_of_the_ticks() {
+    let gauge = Gauge::new(0.0, 100.0)
+        .digits(3)
+        .glyph_style(GlyphStyle::Ascii);
+    // Three digits are 17 columns wide, the ring has 5 + 19 + 5 ticks.
+    assert_eq!(lit_ticks(&gauge.render(0.0).unwrap()), 0);
+    assert_eq!(lit_ticks(&gauge.render(50.0).unwrap()), 15);
+    assert_eq!(lit_ticks(&gauge.render(100.0).unwrap()), 29);
+    assert_eq!(lit_ticks(&gauge.render(-5.0).unwrap()), 0);
+    assert_eq!(lit_ticks(&gauge.render(f64::NAN).unwrap()), 0);
+}
+
+#[test]
+fn gauge_ring_runs_from_bottom_left_to_bottom_right() {
+    let gauge = Gauge::new(0.0, 1.0)
+        .digits(1)
+        .glyph_style(GlyphStyle::Light)
+        .render(0.4)
+        .unwrap();
+
+    assert_eq!(
+        gauge.lines().collect::<Vec<_>>(),
+        [
+            "┌──╌╌╌╌╌┐",
+            "│ ┏━━━┓ ╎",
+            "│ ┃   ┃ ╎",
+            "│ ┃   ┃ ╎",
+            "│ ┃   ┃ ╎",
+            "│ ┗━━━┛ ╎",
+        ]
+    );
+
+    let low = Gauge::new(0.0, 1.0).digits(1).render(0.1).unwrap();
+    let lines = low.lines().collect::<Vec<_>>();
+    assert!(lines[5].starts_with('┃') && lines[4].starts_with('┃'));
+    assert!(lines[3].starts_with('╏'));
+}
+
+#[test]
+fn gauge_number_style() {
+    let style = NumberStyle::new().sign(SignPolicy::Column);
+    let gauge = Gauge::new(-10.0, 10.0).digits(2).number_style(style);
+
+    let negative = gauge.render(-5.0).unwrap();
+    let positive = gauge.render(5.0).unwrap();
+    assert_eq!(
+        negative.lines().next().unwrap().chars().count(),
+        positive.lines().next().unwrap().chars().count()
+    );
+    assert_eq!(gauge.render(-100.0), None);
+}
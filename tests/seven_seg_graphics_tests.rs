@@ -0,0 +1,96 @@
+#![cfg(feature = "embedded-graphics")]
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    pixelcolor::{BinaryColor, Rgb565},
+    prelude::*,
+};
+use seven_seg::{SegmentDisplay, SegmentStyle};
+
+fn small_style() -> SegmentStyle<BinaryColor> {
+    SegmentStyle::new(Size::new(5, 7), BinaryColor::On).digit_spacing(3)
+}
+
+#[test]
+fn sevseg_graphics_eight_dp() {
+    let mut display = MockDisplay::new();
+    let next = SegmentDisplay::new("8.", Point::zero(), small_style())
+        .unwrap()
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_pattern(&[
+        " ###    ", "#   #   ", "#   #   ", " ###    ", "#   #   ", "#   #   ", " ###  # ",
+    ]);
+    assert_eq!(next, Point::new(8, 0));
+}
+
+#[test]
+fn sevseg_graphics_digits() {
+    let mut display = MockDisplay::new();
+    SegmentDisplay::new("-1", Point::new(1, 1), small_style())
+        .unwrap()
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_pattern(&[
+        "              ",
+        "              ",
+        "             #",
+        "             #",
+        "  ###         ",
+        "             #",
+        "             #",
+    ]);
+}
+
+#[test]
+fn sevseg_graphics_off_color() {
+    let style = small_style().off_color(BinaryColor::Off);
+    let mut display = MockDisplay::new();
+    SegmentDisplay::new("1", Point::zero(), style)
+        .unwrap()
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_pattern(&[
+        " ...    ", ".   #   ", ".   #   ", " ...    ", ".   #   ", ".   #   ", " ...  . ",
+    ]);
+}
+
+#[test]
+fn sevseg_graphics_segment_width_color() {
+    let style = SegmentStyle::new(Size::new(6, 10), Rgb565::RED)
+        .segment_width(2)
+        .digit_spacing(2);
+    let mut display = MockDisplay::new();
+    SegmentDisplay::new("7", Point::zero(), style)
+        .unwrap()
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_pattern(&[
+        "  RR  ", "  RR  ", "    RR", "    RR", "      ", "      ", "    RR", "    RR",
+    ]);
+}
+
+#[test]
+fn sevseg_graphics_bounding_box() {
+    let display = SegmentDisplay::new("12.34", Point::new(2, 3), small_style()).unwrap();
+    assert_eq!(display.bounding_box().top_left, Point::new(2, 3));
+    assert_eq!(display.bounding_box().size, Size::new(4 * 8 - 3, 7));
+
+    let display = SegmentDisplay::new("8.", Point::zero(), small_style()).unwrap();
+    assert_eq!(display.bounding_box().size, Size::new(7, 7));
+
+    let style = small_style().off_color(BinaryColor::Off);
+    let display = SegmentDisplay::new("1", Point::zero(), style).unwrap();
+    assert_eq!(display.bounding_box().size, Size::new(7, 7));
+
+    let display = SegmentDisplay::new("", Point::zero(), style).unwrap();
+    assert_eq!(display.bounding_box().size, Size::new(0, 7));
+}
+
+#[test]
+fn sevseg_graphics_invalid() {
+    assert!(SegmentDisplay::new("1a", Point::zero(), small_style()).is_none());
+}
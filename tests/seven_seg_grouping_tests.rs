@@ -0,0 +1,142 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+fn grouping(grouping: Grouping) -> NumberStyle {
+    NumberStyle::new().grouping(grouping)
+}
+
+#[test]
+fn grouping_decimal_separator() {
+    let comma = NumberStyle::new().point(',');
+
+    assert_eq!(
+        sevseg_four_dp_with("1.234,5", comma),
+        sevseg_four_dp(1234.5)
+    );
+    assert_eq!(sevseg_four_dp_with("12,5", comma), sevseg_four_dp(12.5));
+    assert_eq!(sevseg_four_dp_with("-0,25", comma), sevseg_four_dp(-0.25));
+    assert_eq!(sevseg_four_dp_with(12.5, comma), sevseg_four_dp(125));
+    assert_eq!(
+        sevseg_four_dp_with(12.5, NumberStyle::new()),
+        sevseg_four_dp(12.5)
+    );
+}
+
+#[test]
+fn grouping_empty_and_leading_separators() {
+    let comma = NumberStyle::new().point(',');
+
+    assert_eq!(
+        sevseg_four_dp_with("", NumberStyle::new()),
+        "┏━━━┓ ┏━━━┓ ┏━━━┓ ┏━━━┓\n\
+         ┃   ┃ ┃   ┃ ┃   ┃ ┃   ┃\n\
+         ┃   ┃ ┃   ┃ ┃   ┃ ┃   ┃\n\
+         ┃   ┃ ┃   ┃ ┃   ┃ ┃   ┃\n\
+         ┗━━━┛ ┗━━━┛ ┗━━━┛ ┗━━━┛\n"
+    );
+    assert_eq!(
+        sevseg_four_dp_with(".5", NumberStyle::new()),
+        sevseg_four_dp(0.5)
+    );
+    assert_eq!(sevseg_four_dp_with(",5", comma), sevseg_four_dp(0.5));
+    assert_eq!(
+        sevseg_four_dp_with("-.5", NumberStyle::new()),
+        sevseg_four_dp(-0.5)
+    );
+    assert_eq!(
+        sevseg_four_dp_with(",5", comma.grouping(Grouping::Gap)),
+        sevseg_four_dp(0.5)
+    );
+
+    assert_eq!(
+        sevseg_four_dp_html_with(".5", NumberStyle::new()),
+        sevseg_four_dp_html(0.5)
+    );
+    assert_eq!(
+        sevseg_four_dp_html_with(",5", comma),
+        sevseg_four_dp_html(0.5)
+    );
+    assert!(sevseg_four_dp_html_with("", NumberStyle::new()).starts_with("<div class=\"sevseg\">"));
+}
+
+#[test]
+fn grouping_point() {
+    let style = grouping(Grouping::Point);
+
+    assert_eq!(
+        sevseg_number_with(&1234567.5, 9, style).unwrap(),
+        render("1.234.567.50")
+    );
+    assert_eq!(sevseg_number_with(&123, 3, style).unwrap(), render("123"));
+    assert_eq!(
+        sevseg_number_with(&12.5, 4, style).unwrap(),
+        render("12.50")
+    );
+    assert_eq!(sevseg_four_dp_with(1234, style), render("1.234."));
+    assert_eq!(sevseg_four_dp_with(123.4, style), sevseg_four_dp(123.4));
+}
+
+#[test]
+fn grouping_point_after_the_sign() {
+    let style = grouping(Grouping::Point);
+
+    assert_eq!(
+        sevseg_number_with(&-1234, 5, style.sign(SignPolicy::Digit)).unwrap(),
+        render("-1.234")
+    );
+    assert_eq!(
+        sevseg_four_dp_with(-1234, style.sign(SignPolicy::Digit)),
+        sevseg_four_dp_with(f64::NAN, style.non_finite(NonFinite::Error))
+    );
+
+    let merged = sevseg_number_with(&-1234, 4, style).unwrap();
+    for (line, positive) in merged
+        .lines()
+        .zip(sevseg_number_with(&1234, 4, style).unwrap().lines())
+    {
+        assert!(line.ends_with(positive.trim_start()));
+    }
+}
+
+#[test]
+fn grouping_gap() {
+    let display = sevseg_number_with(&1234, 4, grouping(Grouping::Gap)).unwrap();
+
+    let expected = render("1")
+        .lines()
+        .zip(render("234").lines())
+        .map(|(one, rest)| format!("{}  {}\n", one, rest))
+        .collect::<String>();
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn grouping_apostrophe() {
+    let display = sevseg_number_with(&12345.6, 6, grouping(Grouping::Apostrophe)).unwrap();
+    let gap = sevseg_number_with(&12345.6, 6, grouping(Grouping::Gap)).unwrap();
+
+    let mut lines = display.lines().zip(gap.lines());
+    let (first, first_gap) = lines.next().unwrap();
+    assert_eq!(first, first_gap.replacen("┓  ", "┓╻ ", 1));
+    for (line, gap_line) in lines {
+        assert_eq!(line, gap_line);
+    }
+}
+
+#[test]
+fn grouping_html() {
+    let gap = sevseg_four_dp_html_with(1234, grouping(Grouping::Gap));
+    assert_eq!(gap.matches("sevseg-gap").count(), 1);
+    assert!(gap.contains("</span></span>\n<span class=\"sevseg-gap\"></span>\n<span"));
+
+    let apostrophe = sevseg_four_dp_html_with(-1234, grouping(Grouping::Apostrophe));
+    assert_eq!(apostrophe.matches("sevseg-apostrophe").count(), 1);
+
+    assert_eq!(
+        sevseg_four_dp_html_with(123.4, grouping(Grouping::Gap)),
+        sevseg_four_dp_html(123.4)
+    );
+    assert!(SEVSEG_CSS.contains(".sevseg-gap"));
+}
@@ -0,0 +1,82 @@
+use seven_seg::{sevseg_four_dp_html, sevseg_four_html, Segments};
+
+#[test]
+fn sevseg_html_eight() {
+    assert_eq!(
+        sevseg_four_html("8888")
+            .unwrap()
+            .matches("sevseg-on")
+            .count(),
+        28
+    );
+}
+
+#[test]
+fn sevseg_html_padded_zeros() {
+    let html = sevseg_four_html("-").unwrap();
+    assert_eq!(html.matches("<span class=\"sevseg-digit\">").count(), 4);
+    assert_eq!(html.matches("sevseg-on").count(), 6 * 3 + 1);
+    assert!(html.contains(
+        "<span class=\"sevseg-digit\">\
+         <span class=\"sevseg-a sevseg-off\"></span>\
+         <span class=\"sevseg-b sevseg-off\"></span>\
+         <span class=\"sevseg-c sevseg-off\"></span>\
+         <span class=\"sevseg-d sevseg-off\"></span>\
+         <span class=\"sevseg-e sevseg-off\"></span>\
+         <span class=\"sevseg-f sevseg-off\"></span>\
+         <span class=\"sevseg-g sevseg-on\"></span></span>\n</div>\n"
+    ));
+}
+
+#[test]
+fn sevseg_html_no_dp() {
+    assert!(!sevseg_four_html("12").unwrap().contains("sevseg-dp"));
+}
+
+#[test]
+fn sevseg_html_invalid() {
+    assert_eq!(sevseg_four_html(""), None);
+    assert_eq!(sevseg_four_html("12345"), None);
+    assert_eq!(sevseg_four_html("1a"), None);
+}
+
+#[test]
+fn sevseg_html_dp_point_pos() {
+    let html = sevseg_four_dp_html(12.5_f64);
+    let digits = html
+        .split("<span class=\"sevseg-digit\">")
+        .skip(1)
+        .collect::<Vec<_>>();
+    assert_eq!(digits.len(), 4);
+    assert!(digits[1].contains("sevseg-dp sevseg-on"));
+    assert_eq!(html.matches("sevseg-dp sevseg-on").count(), 1);
+    assert!(!html.contains("sevseg-sign"));
+}
+
+#[test]
+fn sevseg_html_dp_integer() {
+    let html = sevseg_four_dp_html(7_u8);
+    let digits = html
+        .split("<span class=\"sevseg-digit\">")
+        .skip(1)
+        .collect::<Vec<_>>();
+    assert!(digits[0].contains("sevseg-dp sevseg-on"));
+}
+
+#[test]
+fn sevseg_html_dp_negative() {
+    let html = sevseg_four_dp_html(-12_i16);
+    assert_eq!(html.matches("sevseg-sign").count(), 1);
+    assert!(html.find("sevseg-sign").unwrap() < html.find("sevseg-digit").unwrap());
+}
+
+#[test]
+fn segments_digits() {
+    assert_eq!(Segments::from_char('0').unwrap().bits(), 0x3f);
+    assert_eq!(Segments::from_char('8').unwrap().bits(), 0x7f);
+    assert!(Segments::from_char('7')
+        .unwrap()
+        .contains(Segments::A | Segments::B));
+    assert!(!Segments::from_char('7').unwrap().contains(Segments::F));
+    assert!(Segments::NONE.is_empty());
+}
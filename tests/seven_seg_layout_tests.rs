@@ -0,0 +1,205 @@
+use seven_seg::*;
+
+#[test]
+fn dashboard_grid_rows() {
+    let dashboard = Dashboard::grid(2)
+        .panel("a")
+        .panel("bb")
+        .panel("ccc")
+        .render();
+
+    assert_eq!(dashboard, "a   bb\nccc   \n");
+}
+
+#[test]
+fn dashboard_grid_column_widths() {
+    let dashboard = Dashboard::grid(2)
+        .column_spacing(2)
+        .panel("1\n2")
+        .panel("x")
+        .panel("long")
+        .panel("y")
+        .render();
+
+    assert_eq!(dashboard, "1     x\n2      \nlong  y\n");
+}
+
+#[test]
+fn dashboard_flow_wraps() {
+    let dashboard = Dashboard::flow(7)
+        .panel("aaa")
+        .panel("bbb")
+        .panel("ccc")
+        .render();
+
+    assert_eq!(dashboard, "aaa bbb\nccc    \n");
+}
+
+#[test]
+fn dashboard_flow_wider_panel() {
+    let dashboard = Dashboard::flow(2).panel("aaa").panel("b").render();
+
+    assert_eq!(dashboard, "aaa\nb  \n");
+}
+
+#[test]
+fn dashboard_row_spacing() {
+    let dashboard = Dashboard::grid(1)
+        .row_spacing(1)
+        .panel("a")
+        .panel("b")
+        .render();
+
+    assert_eq!(dashboard, "a\n \nb\n");
+}
+
+#[test]
+fn dashboard_borders() {
+    for (border, expected) in [
+        (Border::Light, "┌─┐\n│7│\n└─┘\n"),
+        (Border::Heavy, "┏━┓\n┃7┃\n┗━┛\n"),
+        (Border::Rounded, "╭─╮\n│7│\n╰─╯\n"),
+        (Border::Double, "╔═╗\n║7║\n╚═╝\n"),
+    ] {
+        assert_eq!(
+            Dashboard::grid(1).border(border).panel("7").render(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn dashboard_labels_centered() {
+    let dashboard = Dashboard::grid(2)
+        .labeled_panel("x", "label")
+        .labeled_panel("wide panel", "ab")
+        .render();
+
+    assert_eq!(dashboard, "  x   wide panel\nlabel     ab    \n");
+}
+
+#[test]
+fn dashboard_escape_sequences_width() {
+    let dashboard = Dashboard::grid(2)
+        .panel("\x1b[31m8\x1b[0m")
+        .panel("9")
+        .render();
+
+    assert_eq!(dashboard, "\x1b[31m8\x1b[0m 9\n");
+}
+
+#[test]
+fn dashboard_sevseg_panels() {
+    let four = sevseg_four("1234").unwrap();
+    let dashboard = Dashboard::grid(1).panel(four.as_str()).render();
+
+    assert_eq!(dashboard, four);
+}
+
+#[test]
+fn dashboard_push_and_clear() {
+    let mut dashboard = Dashboard::grid(3);
+    dashboard.push("1", None::<&str>);
+    dashboard.push("2", Some("two"));
+    assert_eq!(dashboard.render(), "1  2 \n  two\n");
+
+    dashboard.clear();
+    assert_eq!(dashboard.render(), "");
+}
+
+#[test]
+fn readout_plain() {
+    assert_eq!(Readout::new("12\n34").render(), "12\n34\n");
+}
+
+#[test]
+fn readout_text_unit_on_bottom_line() {
+    let readout = Readout::new("a\nb\nc").unit("MHz").render();
+
+    assert_eq!(readout, "a    \nb    \nc MHz\n");
+}
+
+#[test]
+fn readout_titles() {
+    let above = Readout::new("88888").title("T").render();
+    let below = Readout::new("88888")
+        .title("T")
+        .title_position(TitlePosition::Below)
+        .render();
+
+    assert_eq!(above, "  T  \n88888\n");
+    assert_eq!(below, "88888\n  T  \n");
+}
+
+#[test]
+fn readout_wide_title() {
+    let readout = Readout::new("8").title("title").render();
+
+    assert_eq!(readout, "title\n  8  \n");
+}
+
+#[test]
+fn readout_segment_unit_degree() {
+    let display = sevseg_one("5").unwrap();
+    let readout = Readout::new(display.as_str())
+        .unit("°")
+        .unit_style(UnitStyle::Segments)
+        .render();
+
+    assert_eq!(
+        readout.lines().collect::<Vec<_>>(),
+        [
+            "┏━━━╸ ┏━━━┓",
+            "┃     ┃   ┃",
+            "┗━━━┓ ┗━━━┛",
+            "    ┃      ",
+            "╺━━━┛      ",
+        ]
+    );
+}
+
+#[test]
+fn readout_segment_unit_same_height_as_digits() {
+    let display = sevseg_four("1234").unwrap();
+    for unit in ["°C", "°F", "%", "Hz"] {
+        let readout = Readout::new(display.as_str())
+            .unit(unit)
+            .unit_style(UnitStyle::Segments)
+            .render();
+        assert_eq!(readout.lines().count(), 5, "{}", unit);
+    }
+}
+
+#[test]
+fn readout_segment_unit_falls_back_to_text() {
+    let segments = Readout::new("8\n8")
+        .unit("%")
+        .unit_style(UnitStyle::Segments);
+    let text = Readout::new("8\n8").unit("%");
+
+    assert_eq!(segments.render(), text.render());
+}
+
+#[test]
+fn readout_as_dashboard_panel() {
+    let dashboard = Dashboard::grid(2)
+        .panel(Readout::new("1").unit("V"))
+        .panel(Readout::new("2").unit("A"))
+        .render();
+
+    assert_eq!(dashboard, "1 V 2 A\n");
+}
+
+#[test]
+fn segments_from_unit_char() {
+    assert_eq!(
+        Segments::from_unit_char('°').unwrap(),
+        Segments::A | Segments::B | Segments::F | Segments::G
+    );
+    assert_eq!(
+        Segments::from_unit_char('F').unwrap(),
+        Segments::A | Segments::E | Segments::F | Segments::G
+    );
+    assert_eq!(Segments::from_unit_char('8'), Segments::from_char('8'));
+    assert_eq!(Segments::from_unit_char('%'), None);
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "crossterm")]
+use seven_seg::{sevseg_four, sevseg_frame_size, LiveDisplay};
+use std::io::{self, Write};
+
+/// Records the output of every flush, one frame per entry.
+#[derive(Default)]
+struct Recorder {
+    pending: Vec<u8>,
+    frames: Vec<String>,
+}
+
+impl Write for Recorder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let frame = String::from_utf8(std::mem::take(&mut self.pending)).unwrap();
+        self.frames.push(frame);
+        Ok(())
+    }
+}
+
+fn draw(live: &mut LiveDisplay<Recorder>, frame: &str) -> String {
+    live.draw(frame).unwrap();
+    live.get_ref().frames.last().unwrap().clone()
+}
+
+#[test]
+fn sevseg_live_first_frame_complete() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    assert_eq!(draw(&mut live, "ab\ncd\n"), "\x1b[1;1Hab\x1b[2;1Hcd");
+}
+
+#[test]
+fn sevseg_live_same_frame_nothing() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    let frame = sevseg_four("1234").unwrap();
+    draw(&mut live, &frame);
+    assert_eq!(draw(&mut live, &frame), "");
+}
+
+#[test]
+fn sevseg_live_changed_runs() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    draw(&mut live, "abcdef\n");
+    assert_eq!(draw(&mut live, "aXcdYZ\n"), "\x1b[1;2HX\x1b[1;5HYZ");
+}
+
+#[test]
+fn sevseg_live_changed_digit() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    draw(&mut live, &sevseg_four("1230").unwrap());
+    assert_eq!(
+        draw(&mut live, &sevseg_four("1238").unwrap()),
+        "\x1b[3;19H┣━━━┫"
+    );
+}
+
+#[test]
+fn sevseg_live_shrink_clears() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    draw(&mut live, "abc\ndef\n");
+    assert_eq!(draw(&mut live, "ab\n"), "\x1b[1;3H \x1b[2;1H   ");
+}
+
+#[test]
+fn sevseg_live_origin() {
+    let mut live = LiveDisplay::new(Recorder::default()).origin(10, 4);
+    assert_eq!(draw(&mut live, "a\n"), "\x1b[5;11Ha");
+}
+
+#[test]
+fn sevseg_live_colors() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    assert_eq!(
+        draw(&mut live, "\x1b[31mab\x1b[0mc\n"),
+        "\x1b[1;1H\x1b[31mab\x1b[0mc"
+    );
+    assert_eq!(
+        draw(&mut live, "\x1b[32mab\x1b[0mc\n"),
+        "\x1b[1;1H\x1b[32mab\x1b[0m"
+    );
+}
+
+#[test]
+fn sevseg_live_other_escapes_dropped() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    assert_eq!(draw(&mut live, "\x1b[2J\x1b[sab\x1b[u\n"), "\x1b[1;1Hab");
+}
+
+#[test]
+fn sevseg_live_invalidate() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    draw(&mut live, "ab\n");
+    live.invalidate();
+    assert_eq!(draw(&mut live, "ab\n"), "\x1b[1;1Hab");
+}
+
+#[test]
+fn sevseg_live_set_origin() {
+    let mut live = LiveDisplay::new(Recorder::default());
+    draw(&mut live, "ab\n");
+    live.set_origin(3, 1);
+    assert_eq!(draw(&mut live, "ab\n"), "\x1b[2;4Hab");
+}
+
+#[test]
+fn sevseg_live_frame_size() {
+    assert_eq!(sevseg_frame_size("ab\ncde\n\x1b[1m\n"), (3, 3));
+    assert_eq!(sevseg_frame_size(""), (0, 0));
+}
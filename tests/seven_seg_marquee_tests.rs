@@ -0,0 +1,105 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+#[test]
+fn marquee_scrolls_left() {
+    let frames = Marquee::new("123", 2).unwrap().collect::<Vec<_>>();
+    let expected = ["  ", " 1", "12", "23", "3 "].map(render);
+
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn marquee_scrolls_right() {
+    let frames = Marquee::new("123", 2)
+        .unwrap()
+        .direction(ScrollDirection::Right)
+        .collect::<Vec<_>>();
+    let expected = ["  ", "3 ", "23", "12", " 1"].map(render);
+
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn marquee_exact_size() {
+    let marquee = Marquee::new("12345", 4).unwrap();
+    assert_eq!(marquee.size_hint(), (9, Some(9)));
+    assert_eq!(marquee.count(), 9);
+}
+
+#[test]
+fn marquee_wraparound_left() {
+    let frames = Marquee::new("123", 2)
+        .unwrap()
+        .gap(1)
+        .wraparound(true)
+        .take(5)
+        .collect::<Vec<_>>();
+    let expected = ["12", "23", "3 ", " 1", "12"].map(render);
+
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn marquee_wraparound_right() {
+    let frames = Marquee::new("123", 2)
+        .unwrap()
+        .direction(ScrollDirection::Right)
+        .gap(2)
+        .wraparound(true)
+        .take(6)
+        .collect::<Vec<_>>();
+    let expected = ["23", "12", " 1", "  ", "3 ", "23"].map(render);
+
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn marquee_wraparound_short_text() {
+    let frames = Marquee::new("1", 4)
+        .unwrap()
+        .gap(0)
+        .wraparound(true)
+        .take(2)
+        .collect::<Vec<_>>();
+
+    assert_eq!(frames, ["1111", "1111"].map(render));
+}
+
+#[test]
+fn marquee_wraparound_endless() {
+    let marquee = Marquee::new("12", 4).unwrap().wraparound(true);
+    assert_eq!(marquee.size_hint(), (usize::MAX, None));
+    assert_eq!(marquee.skip(1000).take(3).count(), 3);
+}
+
+#[test]
+fn marquee_points_and_letters() {
+    let frames = Marquee::new("1.2 °C", 3).unwrap().collect::<Vec<_>>();
+
+    assert_eq!(frames.len(), 8);
+    assert_eq!(frames[3], render("1.2 "));
+    assert!(frames[6].contains("┏━━━╸"));
+}
+
+#[test]
+fn marquee_size() {
+    let frame = Marquee::new("8", 1)
+        .unwrap()
+        .size(DigitSize::LARGE)
+        .nth(1)
+        .unwrap();
+
+    assert_eq!(frame.lines().count(), DigitSize::LARGE.rows());
+    assert!(frame
+        .lines()
+        .all(|line| line.chars().count() == DigitSize::LARGE.columns()));
+}
+
+#[test]
+fn marquee_unknown_character() {
+    assert!(Marquee::new("abc!", 4).is_none());
+    assert!(Marquee::new("", 4).is_some());
+}
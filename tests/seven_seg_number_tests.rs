@@ -0,0 +1,141 @@
+mod common;
+
+use common::render;
+use num::bigint::{BigInt, BigUint};
+use num::rational::Ratio;
+use seven_seg::*;
+
+#[test]
+fn number_primitive_integers() {
+    assert_eq!(sevseg_number(&8023_u16, 4).unwrap(), render("8023"));
+    assert_eq!(sevseg_number(&12_u8, 4).unwrap(), render("12.00"));
+    assert_eq!(sevseg_number(&-12_i64, 4).unwrap(), sevseg_four_dp(-12_i64));
+    assert_eq!(sevseg_number(&i128::MIN, 39).unwrap().lines().count(), 5);
+    assert_eq!(sevseg_number(&80234_u32, 4), None);
+}
+
+#[test]
+fn number_floats_are_rounded() {
+    assert_eq!(sevseg_number(&1.23456_f64, 4).unwrap(), render("1.235"));
+    assert_eq!(sevseg_number(&0.5_f32, 2).unwrap(), render("0.5"));
+    assert_eq!(sevseg_number(&9.9996_f64, 4).unwrap(), render("10.00"));
+    assert_eq!(sevseg_number(&9999.6_f64, 4), None);
+    assert_eq!(sevseg_number(&-0.0001_f64, 3).unwrap(), render("0.00"));
+}
+
+#[test]
+fn number_floats_and_ratios_round_alike() {
+    for (float, ratio) in [(2.5, Ratio::new(5, 2)), (-3.5, Ratio::new(-7, 2))] {
+        assert_eq!(sevseg_number(&float, 2), sevseg_number(&ratio, 2));
+    }
+    assert_eq!(sevseg_number(&2.5_f64, 1).unwrap(), render("3"));
+    assert_eq!(sevseg_number(&0.125_f64, 3).unwrap(), render("0.13"));
+}
+
+#[test]
+fn number_big_integers() {
+    let big = BigInt::from(10).pow(40) + 1;
+
+    assert_eq!(sevseg_number(&big, 40), None);
+    assert_eq!(
+        sevseg_number(&big, 41).unwrap(),
+        render(&format!("1{}1", "0".repeat(39)))
+    );
+    assert_eq!(
+        sevseg_number(&-BigInt::from(42), 3).unwrap(),
+        sevseg_number(&-42, 3).unwrap()
+    );
+    assert_eq!(
+        sevseg_number(&BigUint::from(7_u8), 2).unwrap(),
+        render("7.0")
+    );
+}
+
+#[test]
+fn number_ratios_round_half_away_from_zero() {
+    assert_eq!(sevseg_number(&Ratio::new(1, 8), 3).unwrap(), render("0.13"));
+    assert_eq!(
+        sevseg_number(&Ratio::new(-1, 8), 3).unwrap(),
+        sevseg_number(&-0.13, 3).unwrap()
+    );
+    assert_eq!(
+        sevseg_number(&Ratio::new(1_u8, 3), 4).unwrap(),
+        render("0.333")
+    );
+    assert_eq!(
+        sevseg_number(&Ratio::new(i64::MAX, 3), 20).unwrap(),
+        render("3074457345618258602.3")
+    );
+    assert_eq!(
+        sevseg_number(&Ratio::new(BigInt::from(2), BigInt::from(-3)), 4).unwrap(),
+        sevseg_number(&-0.667, 4).unwrap()
+    );
+    assert_eq!(
+        sevseg_number(&Ratio::new(1, -2000), 3).unwrap(),
+        render("0.00")
+    );
+}
+
+#[test]
+fn number_decimal_digits() {
+    assert_eq!(12_u8.decimal_digits(2).unwrap(), "12.00");
+    assert_eq!((-5_i8).decimal_digits(0).unwrap(), "5");
+    assert_eq!(Ratio::new(5, 2).decimal_digits(0).unwrap(), "3");
+    assert_eq!(Ratio::new(1, 200).decimal_digits(2).unwrap(), "0.01");
+    assert_eq!(2.5_f64.decimal_digits(0).unwrap(), "3");
+    assert_eq!((-0.125_f32).decimal_digits(2).unwrap(), "0.13");
+    assert_eq!(2.675_f64.decimal_digits(2).unwrap(), "2.67");
+    assert_eq!(f64::INFINITY.decimal_digits(2), None);
+    assert!(f32::NAN.is_nan());
+    assert!(!Ratio::new(0, -3).is_negative());
+}
+
+#[test]
+fn number_non_finite() {
+    assert_eq!(
+        sevseg_number(&f64::NAN, 4).unwrap(),
+        sevseg_four_dp(f64::NAN)
+    );
+    assert_eq!(
+        sevseg_number(&f32::NEG_INFINITY, 4).unwrap(),
+        sevseg_four_dp(f32::NEG_INFINITY)
+    );
+    assert_eq!(sevseg_number(&f64::INFINITY, 3).unwrap().lines().count(), 5);
+    assert_eq!(sevseg_number(&f64::NEG_INFINITY, 3), None);
+
+    let error = NumberStyle::new().non_finite(NonFinite::Error);
+    assert_eq!(
+        sevseg_number_with(&f64::NAN, 3, error),
+        sevseg_number_with(&f64::INFINITY, 3, error)
+    );
+}
+
+#[test]
+fn number_sign_policy() {
+    let digit = NumberStyle::new().sign(SignPolicy::Digit);
+
+    assert_eq!(
+        sevseg_number_with(&-1.5, 4, digit).unwrap(),
+        render("-1.50")
+    );
+    assert_eq!(
+        sevseg_number_with(&BigInt::from(-123), 4, digit).unwrap(),
+        render("-123")
+    );
+    assert_eq!(sevseg_number_with(&-1234, 4, digit), None);
+    assert_eq!(sevseg_number_with(&-1, 1, digit), None);
+}
+
+#[test]
+fn number_glyphs() {
+    assert_eq!(
+        sevseg_number(&-2.5, 2).unwrap().lines().collect::<Vec<_>>(),
+        [
+            "  ╺━━━┓  ┏━━━╸",
+            "      ┃  ┃    ",
+            "━━┏━━━┛  ┗━━━┓",
+            "  ┃          ┃",
+            "  ┗━━━╸⦁ ╺━━━┛",
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+#[test]
+fn points_on_every_digit() {
+    assert_eq!(
+        sevseg_points("192.168.1.1", 8).unwrap(),
+        render("192.168.1.1")
+    );
+    assert_eq!(sevseg_points("18.10.26", 6).unwrap(), render("18.10.26"));
+    assert_eq!(sevseg_points("1.2.3.4.", 4).unwrap(), render("1.2.3.4."));
+}
+
+#[test]
+fn points_right_aligned_on_the_panel() {
+    assert_eq!(
+        sevseg_points("10.0.0.1", 12).unwrap(),
+        render("       10.0.0.1")
+    );
+    assert_eq!(sevseg_points("", 2).unwrap(), render("  "));
+    assert_eq!(sevseg_points("-1.5", 4).unwrap(), render(" -1.5"));
+}
+
+#[test]
+fn points_on_blank_digits() {
+    assert_eq!(sevseg_points(".5", 2).unwrap(), render(" .5"));
+    assert_eq!(sevseg_points("1..2", 3).unwrap(), render("1. .2"));
+}
+
+#[test]
+fn points_none() {
+    assert_eq!(sevseg_points("192.168.1.1", 7), None);
+    assert_eq!(sevseg_points("v1.2", 4), None);
+    assert_eq!(sevseg_points("1:2", 4), None);
+}
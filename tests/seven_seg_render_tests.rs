@@ -0,0 +1,138 @@
+use seven_seg::*;
+
+#[test]
+fn sevseg_render_same_as_four() {
+    let mut buf = [0; 512];
+    for string in ["0123", "4567", "89--", "-1-7"] {
+        assert_eq!(
+            sevseg_render(string, &mut buf),
+            sevseg_four(string).as_deref()
+        );
+    }
+}
+
+#[test]
+fn sevseg_render_same_as_four_dp() {
+    let mut buf = [0; 512];
+    assert_eq!(
+        sevseg_render("12.50", &mut buf).unwrap(),
+        sevseg_four_dp(12.5_f32)
+    );
+    assert_eq!(
+        sevseg_render("8023.", &mut buf).unwrap(),
+        sevseg_four_dp(8023_u16)
+    );
+}
+
+#[test]
+fn sevseg_render_any_length() {
+    let mut buf = [0; 1024];
+    let text = sevseg_render("1.2.3.4.5.6", &mut buf).unwrap();
+    assert_eq!(text.lines().count(), 5);
+    assert_eq!(text.matches('⦁').count(), 5);
+    assert_eq!(sevseg_render("", &mut buf), Some("\n\n\n\n\n"));
+}
+
+#[test]
+fn sevseg_render_blank() {
+    let mut buf = [0; 64];
+    assert_eq!(
+        sevseg_render(" ", &mut buf),
+        Some("     \n     \n     \n     \n     \n")
+    );
+}
+
+#[test]
+fn sevseg_render_buffer_too_small() {
+    let mut buf = [0; 64];
+    assert_eq!(sevseg_render("88", &mut buf), None);
+}
+
+#[test]
+fn sevseg_render_invalid() {
+    let mut buf = [0; 512];
+    assert_eq!(sevseg_render("1a", &mut buf), None);
+}
+
+#[test]
+fn sevseg_encode_into_buffer() {
+    let mut buf = [0; 4];
+    assert_eq!(
+        sevseg_encode_into("-1.5", &SegmentMap::TM1637, &mut buf),
+        Some(3)
+    );
+    assert_eq!(buf, [0x40, 0x86, 0x6d, 0x00]);
+    assert_eq!(
+        sevseg_encode_into("12345", &SegmentMap::TM1637, &mut buf),
+        None
+    );
+    assert_eq!(
+        sevseg_encode_into("1x", &SegmentMap::TM1637, &mut buf),
+        None
+    );
+}
+
+#[test]
+fn sevseg_render_with_heavy_normal_same_as_render() {
+    let mut buf = [0; 512];
+    let mut buf_with = [0; 512];
+    assert_eq!(
+        sevseg_render_with("-80.2", DigitSize::NORMAL, GlyphStyle::Heavy, &mut buf_with),
+        sevseg_render("-80.2", &mut buf)
+    );
+}
+
+#[test]
+fn sevseg_render_with_light() {
+    let mut buf = [0; 512];
+    assert_eq!(
+        sevseg_render_with("8.", DigitSize::NORMAL, GlyphStyle::Light, &mut buf),
+        Some("┌───┐ \n│   │ \n├───┤ \n│   │ \n└───┘•\n")
+    );
+}
+
+#[test]
+fn sevseg_render_with_ascii() {
+    let mut buf = [0; 512];
+    assert_eq!(
+        sevseg_render_with("8.", DigitSize::SMALL, GlyphStyle::Ascii, &mut buf),
+        Some("+-+ \n| | \n+-+ \n| | \n+-+.\n")
+    );
+}
+
+#[test]
+fn sevseg_render_with_styles_decode() {
+    let mut buf = [0; 1024];
+    for style in [GlyphStyle::Heavy, GlyphStyle::Light, GlyphStyle::Ascii] {
+        let text = sevseg_render_with("1234.", DigitSize::NORMAL, style, &mut buf).unwrap();
+        let decoded = sevseg_decode(text).unwrap();
+        assert_eq!(decoded.to_string(), "1234.", "{:?}", style);
+    }
+}
+
+#[test]
+fn sevseg_render_with_small_buffer() {
+    let mut buf = [0; 16];
+    assert_eq!(
+        sevseg_render_with("1", DigitSize::HUGE, GlyphStyle::Ascii, &mut buf),
+        None
+    );
+}
+
+#[test]
+fn sevseg_render_buffer_len() {
+    for size in DigitSize::ALL.into_iter().chain([DigitSize::new(9, 1)]) {
+        for style in [GlyphStyle::Heavy, GlyphStyle::Light, GlyphStyle::Ascii] {
+            for text in ["", "8", "8.8.8.", "-1.", "...", "0123456789 -"] {
+                let mut buf = vec![0; size.buffer_len(text.chars().count())];
+                assert!(
+                    sevseg_render_with(text, size, style, &mut buf).is_some(),
+                    "{:?} {:?} {:?}",
+                    text,
+                    size,
+                    style
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+fn assert_with_e(display: &str, mantissa: &str, exponent: &str) {
+    let mantissa = render(mantissa);
+    let exponent = render(exponent);
+
+    for ((line, mantissa_line), exponent_line) in
+        display.lines().zip(mantissa.lines()).zip(exponent.lines())
+    {
+        assert!(line.starts_with(mantissa_line));
+        assert!(line.ends_with(exponent_line));
+    }
+}
+
+#[test]
+fn sci_without_e() {
+    assert_eq!(sevseg_sci(0.000012, 4).unwrap(), render("1.2-5"));
+    assert_eq!(sevseg_sci(9.96e-10, 4).unwrap(), render("1.0-9"));
+}
+
+#[test]
+fn sci_with_e() {
+    assert_with_e(&sevseg_sci(0.000012, 6).unwrap(), "1.20", "-5");
+    assert_with_e(&sevseg_sci(15_000_000, 4).unwrap(), "1.5", "7");
+    assert_with_e(&sevseg_sci(6.02214076e23, 8).unwrap(), "6.0221", "23");
+    assert_with_e(&sevseg_sci(0, 4).unwrap(), "0.0", "0");
+}
+
+#[test]
+fn sci_rounding_carries_into_the_exponent() {
+    assert_with_e(&sevseg_sci(9.999e9, 4).unwrap(), "1", "10");
+    assert_with_e(&sevseg_sci(9.96, 4).unwrap(), "1.0", "1");
+}
+
+#[test]
+fn sci_rounding_carries_into_a_shorter_exponent() {
+    assert_with_e(&sevseg_sci(9.96e-10, 6).unwrap(), "1.00", "-9");
+    assert_with_e(&sevseg_sci(9.96e-100, 7).unwrap(), "1.00", "-99");
+}
+
+#[test]
+fn sci_negative_mantissa_merges_the_minus() {
+    let display = sevseg_sci(-0.000012, 4).unwrap();
+    let positive = sevseg_sci(0.000012, 4).unwrap();
+
+    assert_ne!(display, positive);
+    for (line, positive_line) in display.lines().zip(positive.lines()) {
+        assert!(line.ends_with(positive_line.trim_start()));
+    }
+}
+
+#[test]
+fn sci_none() {
+    assert_eq!(sevseg_sci(f64::NAN, 6), None);
+    assert_eq!(sevseg_sci(f32::NEG_INFINITY, 6), None);
+    assert_eq!(sevseg_sci(1, 0), None);
+    assert_eq!(sevseg_sci(0.00001, 2), None);
+    assert_eq!(sevseg_sci(1e-100, 4), None);
+}
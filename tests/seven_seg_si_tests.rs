@@ -0,0 +1,108 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+fn labelled(text: &str, prefix: char) -> String {
+    let display = render(text);
+    let bottom = display.lines().count() - 1;
+
+    display
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            let label = if row == bottom { prefix } else { ' ' };
+            format!("{} {}\n", line, label)
+        })
+        .collect()
+}
+
+#[test]
+fn si_without_prefix_is_blank() {
+    assert_eq!(
+        sevseg_si(12, 3, PrefixStyle::Digit).unwrap(),
+        render("12.0 ")
+    );
+    assert_eq!(
+        sevseg_si(12, 3, PrefixStyle::Label).unwrap(),
+        labelled("12.0", ' ')
+    );
+}
+
+#[test]
+fn si_label_prefixes() {
+    assert_eq!(
+        sevseg_si(1500, 4, PrefixStyle::Label).unwrap(),
+        labelled("1.500", 'k')
+    );
+    assert_eq!(
+        sevseg_si(2.5e6, 2, PrefixStyle::Label).unwrap(),
+        labelled("2.5", 'M')
+    );
+    assert_eq!(
+        sevseg_si(999e9, 3, PrefixStyle::Label).unwrap(),
+        labelled("999", 'G')
+    );
+    assert_eq!(
+        sevseg_si(42e12, 4, PrefixStyle::Label).unwrap(),
+        labelled("42.00", 'T')
+    );
+}
+
+#[test]
+fn si_digit_prefixes_are_letters() {
+    let display = sevseg_si(1500, 2, PrefixStyle::Digit).unwrap();
+    let prefix = sevseg_si(1500, 2, PrefixStyle::Label).unwrap();
+
+    assert_eq!(display.lines().count(), prefix.lines().count());
+    assert_ne!(display, sevseg_si(1.5e6, 2, PrefixStyle::Digit).unwrap());
+    assert_ne!(display, sevseg_si(1.5e9, 2, PrefixStyle::Digit).unwrap());
+    assert_ne!(display, sevseg_si(1.5e12, 2, PrefixStyle::Digit).unwrap());
+}
+
+#[test]
+fn si_larger_prefix_when_digits_do_not_fit() {
+    assert_eq!(
+        sevseg_si(512_000, 2, PrefixStyle::Label).unwrap(),
+        labelled("0.5", 'M')
+    );
+    assert_eq!(
+        sevseg_si(1234, 1, PrefixStyle::Label).unwrap(),
+        labelled("1", 'k')
+    );
+}
+
+#[test]
+fn si_rounding_carries_into_the_integer_digits() {
+    assert_eq!(
+        sevseg_si(999.95, 3, PrefixStyle::Label).unwrap(),
+        labelled("1.00", 'k')
+    );
+    assert_eq!(
+        sevseg_si(999_950, 3, PrefixStyle::Label).unwrap(),
+        labelled("1.00", 'M')
+    );
+}
+
+#[test]
+fn si_negative_values_merge_the_minus() {
+    let display = sevseg_si(-1536.0, 3, PrefixStyle::Label).unwrap();
+    let positive = sevseg_si(1536.0, 3, PrefixStyle::Label).unwrap();
+
+    assert_ne!(display, positive);
+    for (line, positive_line) in display.lines().zip(positive.lines()) {
+        assert!(line.ends_with(positive_line.trim_start()));
+    }
+    assert_eq!(
+        sevseg_si(-0.0, 2, PrefixStyle::Label).unwrap(),
+        sevseg_si(0.0, 2, PrefixStyle::Label).unwrap()
+    );
+}
+
+#[test]
+fn si_none() {
+    assert_eq!(sevseg_si(1, 0, PrefixStyle::Digit), None);
+    assert_eq!(sevseg_si(f64::NAN, 4, PrefixStyle::Digit), None);
+    assert_eq!(sevseg_si(f64::INFINITY, 4, PrefixStyle::Label), None);
+    assert_eq!(sevseg_si(12_345e12, 4, PrefixStyle::Label), None);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+fn style(sign: SignPolicy) -> NumberStyle {
+    NumberStyle::new().sign(sign)
+}
+
+#[test]
+fn sign_merged_is_the_default() {
+    for n in [-1.5_f32, -12.0, 0.25, 8023.0, -0.0] {
+        assert_eq!(
+            sevseg_four_dp_with(n, style(SignPolicy::Merged)),
+            sevseg_four_dp(n)
+        );
+    }
+    assert_eq!(SignPolicy::default(), SignPolicy::Merged);
+}
+
+#[test]
+fn sign_digit_takes_a_digit() {
+    let style = style(SignPolicy::Digit);
+
+    assert_eq!(sevseg_four_dp_with(-1.5_f32, style), render("-1.50"));
+    assert_eq!(sevseg_four_dp_with(-12, style), render("-12.0"));
+    assert_eq!(sevseg_four_dp_with(-123_i16, style), render("-123."));
+    assert_eq!(sevseg_four_dp_with(-999.99, style), render("-999."));
+    assert_eq!(sevseg_four_dp_with(1.5_f64, style), sevseg_four_dp(1.5_f64));
+}
+
+#[test]
+fn sign_digit_does_not_drop_integer_digits() {
+    let style = style(SignPolicy::Digit);
+    let error = sevseg_four_dp_with(f64::NAN, NumberStyle::new().non_finite(NonFinite::Error));
+
+    assert_eq!(sevseg_four_dp_with(-1234, style), error);
+    assert_eq!(sevseg_four_dp_with(-1000.5_f64, style), error);
+    assert_eq!(sevseg_four_dp_with(1234, style), sevseg_four_dp(1234));
+}
+
+#[test]
+fn sign_column_keeps_the_width() {
+    let style = style(SignPolicy::Column);
+
+    assert_eq!(sevseg_four_dp_with(-1.5, style), sevseg_four_dp(-1.5));
+    let positive = sevseg_four_dp_with(1.5, style);
+    for (line, merged) in positive.lines().zip(sevseg_four_dp(1.5).lines()) {
+        assert_eq!(line, format!("  {}", merged));
+    }
+    for (line, negative) in positive.lines().zip(sevseg_four_dp(-1.5).lines()) {
+        assert_eq!(line.chars().count(), negative.chars().count());
+    }
+}
+
+#[test]
+fn sign_policy_of_non_finite() {
+    let letters = sevseg_four_dp(f64::NEG_INFINITY);
+
+    assert_eq!(
+        sevseg_four_dp_with(f64::NEG_INFINITY, style(SignPolicy::Digit)),
+        letters
+    );
+    assert_eq!(
+        sevseg_four_dp_with(f64::NEG_INFINITY, style(SignPolicy::Column)),
+        letters
+            .lines()
+            .map(|line| format!("  {}\n", line))
+            .collect::<String>()
+    );
+}
+
+#[test]
+fn sign_policy_html() {
+    let column = sevseg_four_dp_html_with(1.5, style(SignPolicy::Column));
+    assert_eq!(column.matches("sevseg-minus sevseg-off").count(), 1);
+
+    let column = sevseg_four_dp_html_with(-1.5, style(SignPolicy::Column));
+    assert_eq!(column, sevseg_four_dp_html(-1.5));
+
+    let digit = sevseg_four_dp_html_with(-1.5, style(SignPolicy::Digit));
+    assert!(!digit.contains("sevseg-sign"));
+    assert_eq!(digit.matches("sevseg-digit").count(), 4);
+}
+
+#[test]
+fn sign_policy_si() {
+    assert_eq!(
+        sevseg_si_with(-12, 4, PrefixStyle::Digit, SignPolicy::Digit).unwrap(),
+        render("-12.0 ")
+    );
+    assert_eq!(
+        sevseg_si_with(-0.0001, 3, PrefixStyle::Digit, SignPolicy::Digit).unwrap(),
+        render(" 0.0 ")
+    );
+    assert_eq!(
+        sevseg_si_with(12, 3, PrefixStyle::Digit, SignPolicy::Digit),
+        sevseg_si(12, 3, PrefixStyle::Digit)
+    );
+    assert_eq!(
+        sevseg_si_with(-12, 1, PrefixStyle::Digit, SignPolicy::Digit),
+        None
+    );
+
+    let positive = sevseg_si_with(12, 3, PrefixStyle::Digit, SignPolicy::Column).unwrap();
+    for (line, merged) in positive.lines().zip(render("12.0 ").lines()) {
+        assert_eq!(line, format!("  {}", merged));
+    }
+}
+
+#[test]
+fn sign_policy_sci() {
+    assert_eq!(
+        sevseg_sci_with(-0.000012, 5, SignPolicy::Digit).unwrap(),
+        render("-1.2-5")
+    );
+    assert_eq!(
+        sevseg_sci_with(-0.000012, 4, SignPolicy::Merged),
+        sevseg_sci(-0.000012, 4)
+    );
+    assert_eq!(
+        sevseg_sci_with(0.000012, 4, SignPolicy::Column).unwrap(),
+        render("1.2-5")
+            .lines()
+            .map(|line| format!("  {}\n", line))
+            .collect::<String>()
+    );
+}
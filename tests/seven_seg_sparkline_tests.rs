@@ -0,0 +1,101 @@
+use seven_seg::*;
+
+#[test]
+fn sparkline_ring_buffer_drops_the_oldest() {
+    let mut history = Sparkline::new(3);
+    for value in 1..=5 {
+        history.push(f64::from(value));
+    }
+
+    assert_eq!(history.values().collect::<Vec<_>>(), [3.0, 4.0, 5.0]);
+    history.clear();
+    assert_eq!(history.values().count(), 0);
+
+    let mut none = Sparkline::new(0);
+    none.push(1.0);
+    assert_eq!(none.values().count(), 0);
+}
+
+#[test]
+fn sparkline_levels() {
+    let mut history = Sparkline::new(8);
+    for value in [0.0, 1.0, 2.0, 3.0, 4.0, f64::NAN] {
+        history.push(value);
+    }
+
+    assert_eq!(history.line(8), "  ▁▂▃▅▇ ");
+    assert_eq!(history.line(3), "▁▇ ");
+    assert_eq!(Sparkline::new(4).line(2), "  ");
+
+    let mut flat = Sparkline::new(4);
+    flat.push(7.0);
+    flat.push(7.0);
+    assert_eq!(flat.line(3), " ▁▁");
+}
+
+#[test]
+fn sparkline_infinite_values() {
+    let mut history = Sparkline::new(8);
+    for value in [0.0, 2.0, f64::INFINITY, 4.0, f64::NEG_INFINITY, 1.0] {
+        history.push(value);
+    }
+
+    assert_eq!(history.line(6), "▁▃▇▇▁▂");
+    assert_eq!(history.render("123456").lines().last(), Some("▾  ▴  "));
+
+    let mut infinite = Sparkline::new(2);
+    infinite.push(f64::INFINITY);
+    infinite.push(f64::NEG_INFINITY);
+    assert_eq!(infinite.line(2), "▇▁");
+}
+
+#[test]
+fn sparkline_beneath_the_display_width() {
+    let display = sevseg_four_dp(12.5);
+    let mut history = Sparkline::new(100);
+    for value in 0..40 {
+        history.push(f64::from(value % 7));
+    }
+
+    let text = history.render(&display);
+    let lines = text.lines().collect::<Vec<_>>();
+    let width = display.lines().next().unwrap().chars().count();
+
+    assert!(text.starts_with(&display));
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[5].chars().count(), width);
+    assert_eq!(lines[6].chars().count(), width);
+    assert_eq!(lines[6].matches('▴').count(), 1);
+    assert_eq!(lines[6].matches('▾').count(), 1);
+}
+
+#[test]
+fn sparkline_markers_point_out_the_newest_min_and_max() {
+    let mut history = Sparkline::new(10);
+    for value in [5.0, 1.0, 9.0, 1.0, 9.0, 4.0] {
+        history.push(value);
+    }
+
+    let text = history.render("123456");
+    assert_eq!(
+        text.lines().collect::<Vec<_>>(),
+        ["123456", "▃▁▇▁▇▃", "   ▾▴ "]
+    );
+
+    let plain = history.clone().markers(false).render("123456");
+    assert_eq!(plain.lines().count(), 2);
+
+    let mut flat = Sparkline::new(2);
+    flat.push(1.0);
+    assert_eq!(flat.render("12").lines().last(), Some("  "));
+}
+
+#[test]
+fn sparkline_width_ignores_escape_sequences() {
+    let mut history = Sparkline::new(4);
+    history.push(1.0);
+    history.push(2.0);
+
+    let text = history.markers(false).render("\x1b[31m1234\x1b[0m\n");
+    assert_eq!(text.lines().last(), Some("  ▁▇"));
+}
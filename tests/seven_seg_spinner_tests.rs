@@ -0,0 +1,110 @@
+use seven_seg::*;
+
+/// Rows of a digit with a single segment lit.
+fn digit_row(segment: Segments, row: usize) -> &'static str {
+    match (segment, row) {
+        (Segments::A, 0) | (Segments::G, 2) | (Segments::D, 4) => "╺━━━╸",
+        (Segments::B, 0) | (Segments::C, 2) => "    ╻",
+        (Segments::B, 1) | (Segments::C, 3) => "    ┃",
+        (Segments::B, 2) | (Segments::C, 4) => "    ╹",
+        (Segments::F, 0) | (Segments::E, 2) => "╻    ",
+        (Segments::F, 1) | (Segments::E, 3) => "┃    ",
+        (Segments::F, 2) | (Segments::E, 4) => "╹    ",
+        _ => "     ",
+    }
+}
+
+fn frame(segments: &[Segments]) -> String {
+    (0..5)
+        .map(|row| {
+            let line = segments
+                .iter()
+                .map(|&segment| digit_row(segment, row))
+                .collect::<Vec<_>>()
+                .join(" ");
+            line + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn spinner_rotate() {
+    let frames = Spinner::new(SpinnerKind::Rotate, 2)
+        .take(12)
+        .collect::<Vec<_>>();
+
+    for (idx, segment) in [
+        Segments::A,
+        Segments::B,
+        Segments::C,
+        Segments::D,
+        Segments::E,
+        Segments::F,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        assert_eq!(frames[idx], frame(&[segment, segment]));
+    }
+    assert_eq!(frames[..6], frames[6..]);
+}
+
+#[test]
+fn spinner_chase() {
+    let spinner = Spinner::new(SpinnerKind::Chase, 3);
+    assert_eq!(spinner.period(), 10);
+
+    let frames = spinner.take(20).collect::<Vec<_>>();
+    let none = Segments::NONE;
+    let expected = [
+        [Segments::A, none, none],
+        [none, Segments::A, none],
+        [none, none, Segments::A],
+        [none, none, Segments::B],
+        [none, none, Segments::C],
+        [none, none, Segments::D],
+        [none, Segments::D, none],
+        [Segments::D, none, none],
+        [Segments::E, none, none],
+        [Segments::F, none, none],
+    ];
+
+    for (actual, expected) in frames.iter().zip(expected) {
+        assert_eq!(actual, &frame(&expected));
+    }
+    assert_eq!(frames[..10], frames[10..]);
+}
+
+#[test]
+fn spinner_wave() {
+    let frames = Spinner::new(SpinnerKind::Wave, 5)
+        .take(4)
+        .collect::<Vec<_>>();
+    let (a, g, d) = (Segments::A, Segments::G, Segments::D);
+
+    assert_eq!(frames[0], frame(&[a, g, d, g, a]));
+    assert_eq!(frames[1], frame(&[g, a, g, d, g]));
+    assert_eq!(frames[2], frame(&[d, g, a, g, d]));
+    assert_eq!(frames[3], frame(&[g, d, g, a, g]));
+}
+
+#[test]
+fn spinner_size() {
+    let frame = Spinner::new(SpinnerKind::Rotate, 3)
+        .size(DigitSize::HUGE)
+        .next()
+        .unwrap();
+
+    assert_eq!(frame.lines().count(), DigitSize::HUGE.rows());
+    assert_eq!(
+        frame.lines().next().unwrap(),
+        "╺━━━━━━━╸ ╺━━━━━━━╸ ╺━━━━━━━╸"
+    );
+}
+
+#[test]
+fn spinner_endless() {
+    let spinner = Spinner::new(SpinnerKind::Chase, 4);
+    assert_eq!(spinner.size_hint(), (usize::MAX, None));
+    assert_eq!(spinner.skip(10_000).take(2).count(), 2);
+}
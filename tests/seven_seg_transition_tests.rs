@@ -0,0 +1,140 @@
+mod common;
+
+use common::render;
+use seven_seg::*;
+
+#[test]
+fn transition_ends_with_new_value() {
+    for effect in [
+        TransitionEffect::Roll,
+        TransitionEffect::Morph,
+        TransitionEffect::Fade,
+    ] {
+        let frames = Transition::new("0123", "4567")
+            .unwrap()
+            .effect(effect)
+            .steps(6)
+            .collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames.last().unwrap(), &render("4567"));
+    }
+}
+
+#[test]
+fn transition_exact_size() {
+    let mut transition = Transition::new("1", "2").unwrap().steps(2);
+    assert_eq!(transition.len(), 3);
+    transition.next();
+    assert_eq!(transition.len(), 2);
+    assert_eq!(transition.count(), 2);
+}
+
+#[test]
+fn transition_without_steps() {
+    let frames = Transition::new("1", "2")
+        .unwrap()
+        .steps(0)
+        .collect::<Vec<_>>();
+
+    assert_eq!(frames, [render("2")]);
+}
+
+#[test]
+fn transition_unchanged_digits_stay() {
+    for frame in Transition::new("18", "17")
+        .unwrap()
+        .effect(TransitionEffect::Roll)
+    {
+        for (line, one) in frame.lines().zip(render("1").lines()) {
+            assert!(line.starts_with(one));
+        }
+    }
+}
+
+#[test]
+fn transition_roll_rows() {
+    let frames = Transition::new("1", "7")
+        .unwrap()
+        .effect(TransitionEffect::Roll)
+        .collect::<Vec<_>>();
+    let one = render("1");
+    let seven = render("7");
+    let strip = one.lines().chain(seven.lines()).collect::<Vec<_>>();
+
+    for (step, frame) in frames.iter().enumerate() {
+        let offset = step + 1;
+        assert_eq!(frame.lines().collect::<Vec<_>>(), strip[offset..offset + 5]);
+    }
+}
+
+#[test]
+fn transition_morph_turns_off_then_on() {
+    let frames = Transition::new("7", "4")
+        .unwrap()
+        .effect(TransitionEffect::Morph)
+        .steps(2)
+        .collect::<Vec<_>>();
+
+    // Segment a turns off, then f and g turn on one by one.
+    assert_eq!(frames[0], render("1"));
+    assert_eq!(frames[1], "╻   ╻\n┃   ┃\n╹   ┃\n    ┃\n    ╹\n");
+    assert_eq!(frames[2], render("4"));
+}
+
+#[test]
+fn transition_morph_segments() {
+    let frames = Transition::new("1", "7")
+        .unwrap()
+        .effect(TransitionEffect::Morph)
+        .steps(1)
+        .collect::<Vec<_>>();
+
+    assert_eq!(frames, [render("7"), render("7")]);
+}
+
+#[test]
+fn transition_fade_ghosts() {
+    let frames = Transition::new("7", "1")
+        .unwrap()
+        .effect(TransitionEffect::Fade)
+        .steps(1)
+        .collect::<Vec<_>>();
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(
+        frames[0].lines().next().unwrap(),
+        "\x1b[2m╺\x1b[22m\x1b[2m━\x1b[22m\x1b[2m━\x1b[22m\x1b[2m━\x1b[22m╻"
+    );
+    assert_eq!(frames[1], render("1"));
+}
+
+#[test]
+fn transition_fade_in_after_fade_out() {
+    let frames = Transition::new("1", "7")
+        .unwrap()
+        .effect(TransitionEffect::Fade)
+        .steps(3)
+        .collect::<Vec<_>>();
+
+    assert!(!frames[0].contains('\x1b'));
+    assert!(frames[2].contains("\x1b[2m━\x1b[22m"));
+}
+
+#[test]
+fn transition_aligns_right_and_decimal_point() {
+    let frames = Transition::new("9.", "10.")
+        .unwrap()
+        .effect(TransitionEffect::Fade)
+        .collect::<Vec<_>>();
+
+    assert_eq!(frames.last().unwrap(), &render("10."));
+    for frame in &frames {
+        assert_eq!(frame.lines().count(), 5);
+    }
+}
+
+#[test]
+fn transition_invalid_value() {
+    assert!(Transition::new("1", "x").is_none());
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "ratatui")]
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    widgets::Widget,
+};
+use seven_seg::{
+    sevseg_four, sevseg_four_dp, sevseg_four_dp_with, DigitSize, Grouping, NumberStyle,
+    SevenSegment, SignPolicy,
+};
+
+fn buffer_text(buf: &Buffer) -> String {
+    let mut text = String::new();
+    for y in 0..buf.area.height {
+        for x in 0..buf.area.width {
+            text.push_str(buf[(x, y)].symbol());
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[test]
+fn sevseg_widget_same_as_four() {
+    let area = Rect::new(0, 0, 23, 5);
+    let mut buf = Buffer::empty(area);
+    SevenSegment::new("8023").unwrap().render(area, &mut buf);
+    assert_eq!(buffer_text(&buf), sevseg_four("8023").unwrap());
+}
+
+#[test]
+fn sevseg_widget_same_as_four_dp() {
+    for n in [-1.0, -12.5, -7.25, -0.5, -4.0, -9.0, 12.5, 8023.0, -2345.0] {
+        let text = sevseg_four_dp(n);
+        let width = text.lines().next().unwrap().chars().count() as u16;
+        let area = Rect::new(0, 0, width, 5);
+        let mut buf = Buffer::empty(area);
+        SevenSegment::from_number(n).render(area, &mut buf);
+        assert_eq!(buffer_text(&buf), text, "{}", n);
+    }
+}
+
+#[test]
+fn sevseg_widget_auto_size() {
+    let widget = SevenSegment::new("12").unwrap();
+    assert_eq!(widget.dimensions(DigitSize::LARGE), (15, 7));
+
+    let area = Rect::new(0, 0, 15, 7);
+    let mut buf = Buffer::empty(area);
+    (&widget).render(area, &mut buf);
+    assert_eq!(
+        buf,
+        Buffer::with_lines([
+            "      ╻ ╺━━━━━┓",
+            "      ┃       ┃",
+            "      ┃       ┃",
+            "      ┃ ┏━━━━━┛",
+            "      ┃ ┃      ",
+            "      ┃ ┃      ",
+            "      ╹ ┗━━━━━╸",
+        ])
+    );
+
+    let area = Rect::new(0, 0, 8, 6);
+    let mut buf = Buffer::empty(area);
+    (&widget).render(area, &mut buf);
+    assert_eq!(
+        buf,
+        Buffer::with_lines([
+            "  ╻ ╺━┓ ",
+            "  ┃   ┃ ",
+            "  ┃ ┏━┛ ",
+            "  ┃ ┃   ",
+            "  ╹ ┗━╸ ",
+            "        ",
+        ])
+    );
+}
+
+#[test]
+fn sevseg_widget_center_clipped() {
+    let area = Rect::new(0, 0, 9, 3);
+    let mut buf = Buffer::empty(area);
+    SevenSegment::new("8")
+        .unwrap()
+        .size(DigitSize::NORMAL)
+        .alignment(Alignment::Center)
+        .render(area, &mut buf);
+    assert_eq!(
+        buf,
+        Buffer::with_lines(["  ┏━━━┓  ", "  ┃   ┃  ", "  ┣━━━┫  "])
+    );
+}
+
+#[test]
+fn sevseg_widget_styles() {
+    let area = Rect::new(0, 0, 5, 5);
+    let mut buf = Buffer::empty(area);
+    SevenSegment::new("1")
+        .unwrap()
+        .size(DigitSize::NORMAL)
+        .style(Style::new().bg(Color::Black))
+        .segment_style(Style::new().fg(Color::Red))
+        .render(area, &mut buf);
+
+    assert_eq!(buf[(0, 0)].bg, Color::Black);
+    assert_eq!(buf[(0, 0)].fg, Color::Reset);
+    assert_eq!(buf[(4, 0)].symbol(), "╻");
+    assert_eq!(buf[(4, 0)].fg, Color::Red);
+    assert_eq!(buf[(4, 0)].bg, Color::Black);
+}
+
+#[test]
+fn sevseg_widget_invalid() {
+    assert!(SevenSegment::new("1:2").is_none());
+}
+
+#[test]
+fn sevseg_widget_sign_column() {
+    let style = NumberStyle::new().sign(SignPolicy::Column);
+    let positive = SevenSegment::from_number_with(1.5, style);
+    let negative = SevenSegment::from_number_with(-1.5, style);
+
+    assert_eq!(
+        positive.dimensions(DigitSize::NORMAL),
+        negative.dimensions(DigitSize::NORMAL)
+    );
+
+    let area = Rect::new(0, 0, 26, 5);
+    let mut buf = Buffer::empty(area);
+    positive.render(area, &mut buf);
+    let expected = sevseg_four_dp_with(1.5, style);
+    assert_eq!(buffer_text(&buf), expected);
+}
+
+#[test]
+fn sevseg_widget_grouping() {
+    let style = NumberStyle::new().grouping(Grouping::Gap);
+    let widget = SevenSegment::from_number_with(1234, style);
+
+    assert_eq!(
+        widget.dimensions(DigitSize::NORMAL).0,
+        SevenSegment::from_number(1234)
+            .dimensions(DigitSize::NORMAL)
+            .0
+            + 1
+    );
+
+    let area = Rect::new(0, 0, 25, 5);
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf);
+    assert_eq!(buffer_text(&buf), sevseg_four_dp_with(1234, style));
+}